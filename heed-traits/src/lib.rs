@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::error::Error as StdError;
 
 /// A boxed `Send + Sync + 'static` error.
@@ -17,3 +18,11 @@ pub trait BytesDecode<'a> {
 
     fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError>;
 }
+
+/// A trait that represents a custom ordering of the keys of a database.
+///
+/// The comparison is done on the raw bytes of the keys, it must always produce
+/// the same ordering for the same bytes and must never panic.
+pub trait Comparator {
+    fn compare(a: &[u8], b: &[u8]) -> Ordering;
+}
@@ -0,0 +1,41 @@
+use std::borrow::Cow;
+
+use bincode2::config::standard;
+use bincode2::de::BorrowDecode;
+use bincode2::Encode;
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+
+/// Describes a type that is [`Encode`]/[`BorrowDecode`] and uses `bincode` 2.0 to do so.
+///
+/// The data is stored with the standard configuration of bincode 2.0, which is not the format
+/// of the `SerdeBincode` codec, a database written with one can't be read with the other.
+/// It can borrow bytes from the original slice.
+pub struct Bincode2<T>(std::marker::PhantomData<T>);
+
+impl<'a, T: 'a> BytesEncode<'a> for Bincode2<T>
+where
+    T: Encode,
+{
+    type EItem = T;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        bincode2::encode_to_vec(item, standard()).map(Cow::Owned).map_err(Into::into)
+    }
+}
+
+impl<'a, T: 'a> BytesDecode<'a> for Bincode2<T>
+where
+    T: BorrowDecode<'a, ()>,
+{
+    type DItem = T;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        bincode2::borrow_decode_from_slice(bytes, standard())
+            .map(|(item, _read)| item)
+            .map_err(Into::into)
+    }
+}
+
+unsafe impl<T> Send for Bincode2<T> {}
+
+unsafe impl<T> Sync for Bincode2<T> {}
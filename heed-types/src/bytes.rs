@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+
+/// Describes a raw slice of bytes `[u8]` that is passed through unchanged.
+///
+/// Unlike the [`ByteSlice`] type this codec never checks any [memory alignment],
+/// encoding borrows the given slice and decoding returns the stored bytes as is.
+///
+/// [`ByteSlice`]: crate::ByteSlice
+/// [memory alignment]: std::mem::align_of()
+pub struct Bytes;
+
+impl<'a> BytesEncode<'a> for Bytes {
+    type EItem = [u8];
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        Ok(Cow::Borrowed(item))
+    }
+}
+
+impl<'a> BytesDecode<'a> for Bytes {
+    type DItem = &'a [u8];
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,57 @@
+use std::borrow::Cow;
+use std::mem::size_of;
+
+use bytemuck::{cast_slice, cast_slice_mut, try_cast_slice, AnyBitPattern, NoUninit, PodCastError};
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+
+use crate::{FixedSize, SizeMismatch};
+
+/// Describes an array of `N` elements that must be [memory aligned] and
+/// will be reallocated if it is not.
+///
+/// A [`Cow`] type is returned to represent this behavior and decoding
+/// fails if the number of bytes doesn't match exactly `N` elements.
+///
+/// If you need to store arrays of a variable length you must look at
+/// the [`CowSlice`], [`OwnedSlice`] or [`UnalignedSlice`] types.
+///
+/// [memory aligned]: std::mem::align_of()
+/// [`Cow`]: std::borrow::Cow
+/// [`UnalignedSlice`]: crate::UnalignedSlice
+/// [`OwnedSlice`]: crate::OwnedSlice
+/// [`CowSlice`]: crate::CowSlice
+pub struct CowArray<T, const N: usize>(std::marker::PhantomData<T>);
+
+impl<'a, T: NoUninit, const N: usize> BytesEncode<'a> for CowArray<T, N> {
+    type EItem = [T; N];
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        Ok(Cow::Borrowed(cast_slice(&item[..])))
+    }
+}
+
+impl<'a, T: AnyBitPattern + NoUninit, const N: usize> BytesDecode<'a> for CowArray<T, N> {
+    type DItem = Cow<'a, [T; N]>;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        SizeMismatch::check(N * size_of::<T>(), bytes)?;
+
+        match try_cast_slice(bytes) {
+            Ok(items) => Ok(Cow::Borrowed(items.try_into()?)),
+            Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned) => {
+                let mut items = [T::zeroed(); N];
+                cast_slice_mut(&mut items[..]).copy_from_slice(bytes);
+                Ok(Cow::Owned(items))
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+impl<T, const N: usize> FixedSize for CowArray<T, N> {
+    const SIZE: usize = N * size_of::<T>();
+}
+
+unsafe impl<T, const N: usize> Send for CowArray<T, N> {}
+
+unsafe impl<T, const N: usize> Sync for CowArray<T, N> {}
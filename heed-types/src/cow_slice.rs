@@ -1,8 +1,11 @@
 use std::borrow::Cow;
+use std::mem::size_of;
 
 use bytemuck::{pod_collect_to_vec, try_cast_slice, AnyBitPattern, NoUninit, PodCastError};
 use heed_traits::{BoxedError, BytesDecode, BytesEncode};
 
+use crate::SizeMismatch;
+
 /// Describes a slice that must be [memory aligned] and
 /// will be reallocated if it is not.
 ///
@@ -32,9 +35,13 @@ impl<'a, T: AnyBitPattern + NoUninit> BytesDecode<'a> for CowSlice<T> {
     type DItem = Cow<'a, [T]>;
 
     fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        SizeMismatch::check_multiple(size_of::<T>(), bytes)?;
+
         match try_cast_slice(bytes) {
             Ok(items) => Ok(Cow::Borrowed(items)),
-            Err(PodCastError::AlignmentMismatch) => Ok(Cow::Owned(pod_collect_to_vec(bytes))),
+            Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned) => {
+                Ok(Cow::Owned(pod_collect_to_vec(bytes)))
+            }
             Err(error) => Err(error.into()),
         }
     }
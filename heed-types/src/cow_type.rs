@@ -1,8 +1,11 @@
 use std::borrow::Cow;
+use std::mem::size_of;
 
 use bytemuck::{bytes_of, bytes_of_mut, try_from_bytes, AnyBitPattern, NoUninit, PodCastError};
 use heed_traits::{BoxedError, BytesDecode, BytesEncode};
 
+use crate::{FixedSize, SizeMismatch};
+
 /// Describes a type that must be [memory aligned] and
 /// will be reallocated if it is not.
 ///
@@ -38,6 +41,8 @@ impl<'a, T: AnyBitPattern + NoUninit> BytesDecode<'a> for CowType<T> {
     type DItem = Cow<'a, T>;
 
     fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        SizeMismatch::check(size_of::<T>(), bytes)?;
+
         match try_from_bytes(bytes) {
             Ok(item) => Ok(Cow::Borrowed(item)),
             Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned) => {
@@ -50,6 +55,10 @@ impl<'a, T: AnyBitPattern + NoUninit> BytesDecode<'a> for CowType<T> {
     }
 }
 
+impl<T> FixedSize for CowType<T> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
 unsafe impl<T> Send for CowType<T> {}
 
 unsafe impl<T> Sync for CowType<T> {}
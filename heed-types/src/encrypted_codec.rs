@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::{error, fmt};
+
+use chacha20poly1305::aead::{AeadCore, AeadInPlace, KeyInit, OsRng};
+use chacha20poly1305::{Tag, XChaCha20Poly1305, XNonce};
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+use zeroize::Zeroizing;
+
+/// The number of bytes of the random nonce stored in front of every encrypted value.
+const NONCE_LEN: usize = 24;
+
+/// The number of bytes of the authentication tag stored after every encrypted value.
+const TAG_LEN: usize = 16;
+
+thread_local! {
+    /// The keys set by [`EncryptionKey::with`] on this thread, the last one is in use.
+    static KEYRING: RefCell<Vec<XChaCha20Poly1305>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A 256 bits key used by the [`EncryptedCodec`]s of a thread.
+///
+/// Codecs are types without any state, the key is therefore set on the current thread
+/// for the duration of [`Self::with`], e.g. around the transactions of an environment.
+/// Different environments can this way use different keys. The key is zeroized on drop.
+#[derive(Clone)]
+pub struct EncryptionKey {
+    cipher: XChaCha20Poly1305,
+}
+
+impl EncryptionKey {
+    /// Creates a key from its 32 bytes.
+    pub fn new(key: &[u8; 32]) -> EncryptionKey {
+        EncryptionKey { cipher: XChaCha20Poly1305::new(key.into()) }
+    }
+
+    /// Runs `f` with this key used by the [`EncryptedCodec`]s of the current thread.
+    ///
+    /// Calls can be nested, the innermost key is used and the previous one is restored
+    /// when `f` returns or panics. Values must be decoded before `f` returns, which
+    /// includes the ones of lazily decoded entries and of iterators.
+    pub fn with<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Unset;
+
+        impl Drop for Unset {
+            fn drop(&mut self) {
+                KEYRING.with(|keyring| keyring.borrow_mut().pop());
+            }
+        }
+
+        KEYRING.with(|keyring| keyring.borrow_mut().push(self.cipher.clone()));
+        let _unset = Unset;
+        f()
+    }
+}
+
+impl fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EncryptionKey").finish_non_exhaustive()
+    }
+}
+
+/// Runs `f` with the cipher of the key in use on the current thread.
+fn with_cipher<R>(
+    f: impl FnOnce(&XChaCha20Poly1305) -> Result<R, BoxedError>,
+) -> Result<R, BoxedError> {
+    KEYRING.with(|keyring| match keyring.borrow().last() {
+        Some(cipher) => f(cipher),
+        None => Err(MissingEncryptionKey.into()),
+    })
+}
+
+/// Describes a codec that encrypts the bytes of another codec with XChaCha20-Poly1305.
+///
+/// The bytes produced by the inner codec `C` are encrypted with the key set by
+/// [`EncryptionKey::with`] and a random nonce which is stored in front of them. They are
+/// authenticated and decrypted into a new buffer before being decoded by `C`, the decoded
+/// type must therefore not borrow from the bytes. The plaintexts are zeroized once used.
+/// Only the values can be encrypted, the keys must stay ordered.
+///
+/// ```
+/// use heed_traits::{BytesDecode, BytesEncode};
+/// use heed_types::{EncryptedCodec, EncryptionKey, BEU64};
+///
+/// type Secret = EncryptedCodec<BEU64>;
+///
+/// let key = EncryptionKey::new(&[42; 32]);
+/// let other_key = EncryptionKey::new(&[43; 32]);
+///
+/// // A key must be set on the current thread.
+/// assert!(Secret::bytes_encode(&42).is_err());
+///
+/// let mut bytes = key.with(|| Secret::bytes_encode(&42).unwrap().into_owned());
+/// assert_eq!(key.with(|| Secret::bytes_decode(&bytes)).unwrap(), 42);
+///
+/// // Values are never decoded with another key or when tampered.
+/// assert!(other_key.with(|| Secret::bytes_decode(&bytes)).is_err());
+/// *bytes.last_mut().unwrap() ^= 1;
+/// assert!(key.with(|| Secret::bytes_decode(&bytes)).is_err());
+/// ```
+pub struct EncryptedCodec<C>(std::marker::PhantomData<C>);
+
+impl<'a, C> BytesEncode<'a> for EncryptedCodec<C>
+where
+    C: BytesEncode<'a>,
+{
+    type EItem = C::EItem;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        let bytes = C::bytes_encode(item)?;
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        // The buffer is zeroized if the encryption fails before overwriting the plaintext.
+        let mut buffer = Zeroizing::new(Vec::with_capacity(NONCE_LEN + bytes.len() + TAG_LEN));
+        buffer.extend_from_slice(&nonce);
+        buffer.extend_from_slice(&bytes);
+        if let Cow::Owned(bytes) = bytes {
+            drop(Zeroizing::new(bytes));
+        }
+        let tag = with_cipher(|cipher| {
+            Ok(cipher.encrypt_in_place_detached(&nonce, &[], &mut buffer[NONCE_LEN..])?)
+        })?;
+        buffer.extend_from_slice(&tag);
+
+        Ok(Cow::Owned(std::mem::take(&mut *buffer)))
+    }
+}
+
+impl<'a, C, T: 'a> BytesDecode<'a> for EncryptedCodec<C>
+where
+    C: for<'b> BytesDecode<'b, DItem = T>,
+{
+    type DItem = T;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        // Bytes too short to hold a nonce and a tag can't be authenticated either.
+        if bytes.len() < NONCE_LEN + TAG_LEN {
+            return Err(chacha20poly1305::Error.into());
+        }
+
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+        let (ciphertext, tag) = ciphertext.split_at(ciphertext.len() - TAG_LEN);
+
+        let mut buffer = Zeroizing::new(ciphertext.to_vec());
+        with_cipher(|cipher| {
+            Ok(cipher.decrypt_in_place_detached(
+                XNonce::from_slice(nonce),
+                &[],
+                &mut buffer,
+                Tag::from_slice(tag),
+            )?)
+        })?;
+
+        C::bytes_decode(&buffer)
+    }
+}
+
+unsafe impl<C> Send for EncryptedCodec<C> {}
+
+unsafe impl<C> Sync for EncryptedCodec<C> {}
+
+/// The error returned when an [`EncryptedCodec`] is used outside of [`EncryptionKey::with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingEncryptionKey;
+
+impl fmt::Display for MissingEncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("no encryption key is set on this thread")
+    }
+}
+
+impl error::Error for MissingEncryptionKey {}
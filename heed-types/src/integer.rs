@@ -5,6 +5,8 @@ use std::mem::size_of;
 use byteorder::{ByteOrder, ReadBytesExt};
 use heed_traits::{BoxedError, BytesDecode, BytesEncode};
 
+use crate::FixedSize;
+
 pub struct U8;
 
 impl BytesEncode<'_> for U8 {
@@ -23,6 +25,10 @@ impl BytesDecode<'_> for U8 {
     }
 }
 
+impl FixedSize for U8 {
+    const SIZE: usize = 1;
+}
+
 pub struct I8;
 
 impl BytesEncode<'_> for I8 {
@@ -41,6 +47,10 @@ impl BytesDecode<'_> for I8 {
     }
 }
 
+impl FixedSize for I8 {
+    const SIZE: usize = 1;
+}
+
 macro_rules! define_type {
     ($name:ident, $native:ident, $read_method:ident, $write_method:ident) => {
         pub struct $name<O>(PhantomData<O>);
@@ -62,6 +72,10 @@ macro_rules! define_type {
                 bytes.$read_method::<O>().map_err(Into::into)
             }
         }
+
+        impl<O> FixedSize for $name<O> {
+            const SIZE: usize = size_of::<$native>();
+        }
     };
 }
 
@@ -73,3 +87,56 @@ define_type!(I16, i16, read_i16, write_i16);
 define_type!(I32, i32, read_i32, write_i32);
 define_type!(I64, i64, read_i64, write_i64);
 define_type!(I128, i128, read_i128, write_i128);
+
+macro_rules! define_big_endian_alias {
+    ($alias:ident, $name:ident, $native:ident) => {
+        #[doc = concat!("Encodes a [`", stringify!($native), "`] in big-endian order,")]
+        /// so that the byte-wise ordering of LMDB matches the numeric ordering.
+        pub type $alias = $name<byteorder::BigEndian>;
+    };
+}
+
+define_big_endian_alias!(BEU16, U16, u16);
+define_big_endian_alias!(BEU32, U32, u32);
+define_big_endian_alias!(BEU64, U64, u64);
+define_big_endian_alias!(BEU128, U128, u128);
+
+macro_rules! define_signed_big_endian_type {
+    ($name:ident, $native:ident, $read_method:ident, $write_method:ident) => {
+        #[doc = concat!("Encodes an [`", stringify!($native), "`] in big-endian order with its sign bit flipped,")]
+        /// so that the byte-wise ordering of LMDB matches the numeric ordering, negative
+        /// numbers included.
+        ///
+        /// The bytes therefore differ from the two's complement representation stored by
+        /// the generic signed integer codecs with the [`BigEndian`](byteorder::BigEndian) order.
+        pub struct $name;
+
+        impl BytesEncode<'_> for $name {
+            type EItem = $native;
+
+            fn bytes_encode(item: &Self::EItem) -> Result<Cow<[u8]>, BoxedError> {
+                let mut buf = vec![0; size_of::<Self::EItem>()];
+                byteorder::BigEndian::$write_method(&mut buf, *item ^ $native::MIN);
+                Ok(Cow::from(buf))
+            }
+        }
+
+        impl BytesDecode<'_> for $name {
+            type DItem = $native;
+
+            fn bytes_decode(mut bytes: &'_ [u8]) -> Result<Self::DItem, BoxedError> {
+                let item = bytes.$read_method::<byteorder::BigEndian>()?;
+                Ok(item ^ $native::MIN)
+            }
+        }
+
+        impl FixedSize for $name {
+            const SIZE: usize = size_of::<$native>();
+        }
+    };
+}
+
+define_signed_big_endian_type!(BEI16, i16, read_i16, write_i16);
+define_signed_big_endian_type!(BEI32, i32, read_i32, write_i32);
+define_signed_big_endian_type!(BEI64, i64, read_i64, write_i64);
+define_signed_big_endian_type!(BEI128, i128, read_i128, write_i128);
@@ -1,13 +1,24 @@
+use std::borrow::Cow;
 use std::marker;
 
-use heed_traits::BoxedError;
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
 
 /// Lazily decode the data bytes, it can be used to avoid CPU intensive decoding
 /// before making sure we really need to decode it (e.g. based on the key).
+///
+/// Encoding is delegated to the inner codec.
 #[derive(Default)]
 pub struct LazyDecode<C>(marker::PhantomData<C>);
 
-impl<'a, C: 'static> heed_traits::BytesDecode<'a> for LazyDecode<C> {
+impl<'a, C: BytesEncode<'a>> BytesEncode<'a> for LazyDecode<C> {
+    type EItem = C::EItem;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        C::bytes_encode(item)
+    }
+}
+
+impl<'a, C: 'static> BytesDecode<'a> for LazyDecode<C> {
     type DItem = Lazy<'a, C>;
 
     fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
@@ -15,6 +26,10 @@ impl<'a, C: 'static> heed_traits::BytesDecode<'a> for LazyDecode<C> {
     }
 }
 
+unsafe impl<C> Send for LazyDecode<C> {}
+
+unsafe impl<C> Sync for LazyDecode<C> {}
+
 /// Owns bytes that can be decoded on demand.
 #[derive(Copy, Clone)]
 pub struct Lazy<'a, C> {
@@ -22,7 +37,8 @@ pub struct Lazy<'a, C> {
     _phantom: marker::PhantomData<C>,
 }
 
-impl<'a, C: heed_traits::BytesDecode<'a>> Lazy<'a, C> {
+impl<'a, C: BytesDecode<'a>> Lazy<'a, C> {
+    /// Decodes the bytes using the inner codec.
     pub fn decode(&self) -> Result<C::DItem, BoxedError> {
         C::bytes_decode(self.data)
     }
@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+use std::mem::size_of;
+
+use bytemuck::{cast_slice, pod_collect_to_vec, AnyBitPattern, NoUninit};
+use byteorder::{BigEndian, ByteOrder};
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+
+use crate::SizeMismatch;
+
+/// The number of bytes of the big-endian `u32` element count.
+const COUNT_SIZE: usize = size_of::<u32>();
+
+/// Describes a [`Vec`] of types that is stored with its number of elements.
+///
+/// The count is written as a big-endian `u32` before the elements, decoding checks that
+/// the number of remaining bytes matches it. Unlike the [`OwnedSlice`] that infers the
+/// length from the number of bytes, a truncated or extended value is detected and
+/// returns a [`SizeMismatch`] error.
+///
+/// [`OwnedSlice`]: crate::OwnedSlice
+pub struct LenPrefixedSlice<T>(std::marker::PhantomData<T>);
+
+impl<'a, T: NoUninit> BytesEncode<'a> for LenPrefixedSlice<T> {
+    type EItem = [T];
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        let count = u32::try_from(item.len())?;
+        let items: &[u8] = cast_slice(item);
+
+        let mut bytes = vec![0; COUNT_SIZE];
+        BigEndian::write_u32(&mut bytes, count);
+        bytes.extend_from_slice(items);
+        Ok(Cow::Owned(bytes))
+    }
+}
+
+impl<'a, T: AnyBitPattern + NoUninit> BytesDecode<'a> for LenPrefixedSlice<T> {
+    type DItem = Vec<T>;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        if bytes.len() < COUNT_SIZE {
+            return Err(SizeMismatch { expected: COUNT_SIZE, found: bytes.len() }.into());
+        }
+
+        let (count, items) = bytes.split_at(COUNT_SIZE);
+        let count = BigEndian::read_u32(count) as usize;
+        SizeMismatch::check(count.saturating_mul(size_of::<T>()), items)?;
+
+        Ok(pod_collect_to_vec(items))
+    }
+}
+
+unsafe impl<T> Send for LenPrefixedSlice<T> {}
+
+unsafe impl<T> Sync for LenPrefixedSlice<T> {}
@@ -3,48 +3,97 @@
 //! How to choose the right type to store things in this database?
 //! For specific types you can choose:
 //!   - [`Str`] to store [`str`](primitive@str)s
+//!   - [`StrUnchecked`] to read [`TrustedUtf8`] [`str`](primitive@str)s back without validating them
+//!   - [`Bytes`] to store raw `[u8]` payloads untouched
 //!   - [`Unit`] to store `()` types
+//!   - [`Tuple2`] or [`Tuple3`] to build composite keys out of [`FixedSize`] components
 //!   - [`SerdeBincode`] or [`SerdeJson`] to store [`Serialize`]/[`Deserialize`] types
+//!   - `Bincode2`, behind the `bincode2` feature, to store bincode 2.0 `Encode`/`Decode` types
+//!   - `SerdeRmp`, behind the `serde-rmp` feature, to store them as MessagePack
+//!   - `SerdeCbor`, behind the `serde-cbor` feature, to store them as CBOR
+//!   - `SerdePostcard`, behind the `serde-postcard` feature, to store them in the compact postcard format
+//!   - `RkyvCodec`, behind the `rkyv` feature, to read archived types without any copy
+//!   - [`LazyDecode`] to wrap another codec and only decode the data on demand
+//!   - [`OptionCodec`] to wrap another codec and store optional values
+//!   - `ZstdCodec`, behind the `zstd` feature, to wrap another codec and compress its bytes
+//!   - `EncryptedCodec`, behind the `encryption` feature, to wrap another codec and encrypt its bytes
 //!
 //! But if you want to store big types that can be efficiently deserialized then
 //! here is a little table to help you in your quest:
 //!
-//! | Available types    | Encoding type | Decoding type | allocations                                              |
-//! |--------------------|:-------------:|:-------------:|----------------------------------------------------------|
-//! | [`CowSlice`]       | `&[T]`        | `Cow<[T]>`    | will allocate if memory is miss-aligned                  |
-//! | [`CowType`]        | `&T`          | `Cow<T>`      | will allocate if memory is miss-aligned                  |
-//! | [`OwnedSlice`]     | `&[T]`        | `Vec<T>`      | will _always_ allocate                                   |
-//! | [`OwnedType`]      | `&T`          | `T`           | will _always_ allocate                                   |
-//! | [`UnalignedSlice`] | `&[T]`        | `&[T]`        | will _never_ allocate because alignement is always valid |
-//! | [`UnalignedType`]  | `&T`          | `&T`          | will _never_ allocate because alignement is always valid |
+//! | Available types      | Encoding type | Decoding type | allocations                                              |
+//! |----------------------|:-------------:|:-------------:|----------------------------------------------------------|
+//! | [`CowArray`]         | `&[T; N]`     | `Cow<[T; N]>` | will allocate if memory is miss-aligned                  |
+//! | [`CowSlice`]         | `&[T]`        | `Cow<[T]>`    | will allocate if memory is miss-aligned                  |
+//! | [`CowType`]          | `&T`          | `Cow<T>`      | will allocate if memory is miss-aligned                  |
+//! | [`LenPrefixedSlice`] | `&[T]`        | `Vec<T>`      | will _always_ allocate, the length is checked            |
+//! | [`OwnedSlice`]       | `&[T]`        | `Vec<T>`      | will _always_ allocate                                   |
+//! | [`OwnedType`]        | `&T`          | `T`           | will _always_ allocate                                   |
+//! | [`UnalignedSlice`]   | `&[T]`        | `&[T]`        | will _never_ allocate because alignement is always valid |
+//! | [`UnalignedType`]    | `&T`          | `&T`          | will _never_ allocate because alignement is always valid |
 //!
 //! [`Serialize`]: serde::Serialize
 //! [`Deserialize`]: serde::Deserialize
 
+mod bytes;
+mod cow_array;
 mod cow_slice;
 mod cow_type;
 mod integer;
+mod lazy_decode;
+mod len_prefixed_slice;
+mod option_codec;
 mod owned_slice;
 mod owned_type;
 mod str;
+mod tuple;
 mod unaligned_slice;
 mod unaligned_type;
 mod unit;
 
+#[cfg(feature = "bincode2")]
+mod bincode2_codec;
+
+#[cfg(feature = "encryption")]
+mod encrypted_codec;
+
+#[cfg(feature = "rkyv")]
+mod rkyv_codec;
+
 #[cfg(feature = "serde-bincode")]
 mod serde_bincode;
 
+#[cfg(feature = "serde-cbor")]
+mod serde_cbor;
+
 #[cfg(feature = "serde-json")]
 mod serde_json;
 
+#[cfg(feature = "serde-postcard")]
+mod serde_postcard;
+
+#[cfg(feature = "serde-rmp")]
+mod serde_rmp;
+
+#[cfg(feature = "zstd")]
+mod zstd_codec;
+
+use std::{error, fmt};
+
 use heed_traits::BoxedError;
 
+pub use self::bytes::Bytes;
+pub use self::cow_array::CowArray;
 pub use self::cow_slice::CowSlice;
 pub use self::cow_type::CowType;
 pub use self::integer::*;
+pub use self::lazy_decode::{Lazy, LazyDecode};
+pub use self::len_prefixed_slice::LenPrefixedSlice;
+pub use self::option_codec::{InvalidOptionTag, OptionCodec};
 pub use self::owned_slice::OwnedSlice;
 pub use self::owned_type::OwnedType;
-pub use self::str::Str;
+pub use self::str::{Str, StrUnchecked, TrustedUtf8};
+pub use self::tuple::{FixedSize, Tuple2, Tuple3};
 pub use self::unaligned_slice::UnalignedSlice;
 pub use self::unaligned_type::UnalignedType;
 pub use self::unit::Unit;
@@ -59,6 +108,11 @@ pub type ByteSlice = UnalignedSlice<u8>;
 ///
 /// It is appropriate to be used to count keys for example
 /// or to ensure that an entry exist for example.
+///
+/// The bytes are never read, this type only implements [`BytesDecode`]
+/// and therefore can't be used to write entries.
+///
+/// [`BytesDecode`]: heed_traits::BytesDecode
 pub struct DecodeIgnore;
 
 impl heed_traits::BytesDecode<'_> for DecodeIgnore {
@@ -69,7 +123,63 @@ impl heed_traits::BytesDecode<'_> for DecodeIgnore {
     }
 }
 
+/// The error returned when the number of bytes to decode doesn't
+/// match the size of the plain old data type they represent.
+///
+/// For slices, the `expected` number of bytes is the closest multiple
+/// of the size of the elements lower than the `found` one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeMismatch {
+    /// The number of bytes that were expected.
+    pub expected: usize,
+    /// The number of bytes that were found.
+    pub found: usize,
+}
+
+impl SizeMismatch {
+    /// Checks that the bytes are exactly `expected` long.
+    fn check(expected: usize, bytes: &[u8]) -> Result<(), SizeMismatch> {
+        let found = bytes.len();
+        if found == expected {
+            Ok(())
+        } else {
+            Err(SizeMismatch { expected, found })
+        }
+    }
+
+    /// Checks that the bytes can be split in elements of `size` bytes.
+    fn check_multiple(size: usize, bytes: &[u8]) -> Result<(), SizeMismatch> {
+        let found = bytes.len();
+        match found.checked_rem(size) {
+            Some(0) | None => Ok(()),
+            Some(slop) => Err(SizeMismatch { expected: found - slop, found }),
+        }
+    }
+}
+
+impl fmt::Display for SizeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid number of bytes, expected {} but found {}", self.expected, self.found)
+    }
+}
+
+impl error::Error for SizeMismatch {}
+
+#[cfg(feature = "bincode2")]
+pub use self::bincode2_codec::Bincode2;
+#[cfg(feature = "encryption")]
+pub use self::encrypted_codec::{EncryptedCodec, EncryptionKey, MissingEncryptionKey};
+#[cfg(feature = "rkyv")]
+pub use self::rkyv_codec::RkyvCodec;
 #[cfg(feature = "serde-bincode")]
 pub use self::serde_bincode::SerdeBincode;
+#[cfg(feature = "serde-cbor")]
+pub use self::serde_cbor::SerdeCbor;
 #[cfg(feature = "serde-json")]
 pub use self::serde_json::SerdeJson;
+#[cfg(feature = "serde-postcard")]
+pub use self::serde_postcard::SerdePostcard;
+#[cfg(feature = "serde-rmp")]
+pub use self::serde_rmp::SerdeRmp;
+#[cfg(feature = "zstd")]
+pub use self::zstd_codec::ZstdCodec;
@@ -0,0 +1,90 @@
+use std::borrow::Cow;
+use std::{error, fmt};
+
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+
+use crate::SizeMismatch;
+
+/// The tag byte written in front of a `None` value.
+const NONE_TAG: u8 = 0;
+
+/// The tag byte written in front of the bytes of a `Some` value.
+const SOME_TAG: u8 = 1;
+
+/// Describes an optional value, e.g. to tell apart a key that holds no value from a missing key.
+///
+/// A tag byte is always written first, `0` for `None` and `1` for `Some` followed by the
+/// bytes of the inner codec `C`, this way a value whose encoding is empty is still `Some`.
+///
+/// ```
+/// use heed_types::{OptionCodec, Str};
+/// use heed_traits::{BytesDecode, BytesEncode};
+///
+/// type MaybeStr = OptionCodec<Str>;
+///
+/// let bytes = MaybeStr::bytes_encode(&Some("")).unwrap();
+/// assert_eq!(&bytes[..], &[1]);
+/// assert_eq!(MaybeStr::bytes_decode(&bytes).unwrap(), Some(""));
+///
+/// let bytes = MaybeStr::bytes_encode(&None).unwrap();
+/// assert_eq!(&bytes[..], &[0]);
+/// assert_eq!(MaybeStr::bytes_decode(&bytes).unwrap(), None);
+/// ```
+pub struct OptionCodec<C>(std::marker::PhantomData<C>);
+
+impl<'a, C> BytesEncode<'a> for OptionCodec<C>
+where
+    C: BytesEncode<'a>,
+{
+    type EItem = Option<&'a C::EItem>;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        match item {
+            None => Ok(Cow::Borrowed(&[NONE_TAG])),
+            Some(item) => {
+                let bytes = C::bytes_encode(item)?;
+                let mut buffer = Vec::with_capacity(1 + bytes.len());
+                buffer.push(SOME_TAG);
+                buffer.extend_from_slice(&bytes);
+                Ok(Cow::Owned(buffer))
+            }
+        }
+    }
+}
+
+impl<'a, C> BytesDecode<'a> for OptionCodec<C>
+where
+    C: BytesDecode<'a>,
+{
+    type DItem = Option<C::DItem>;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        match bytes.split_first() {
+            Some((&NONE_TAG, [])) => Ok(None),
+            Some((&NONE_TAG, _)) => Err(SizeMismatch { expected: 1, found: bytes.len() }.into()),
+            Some((&SOME_TAG, tail)) => C::bytes_decode(tail).map(Some),
+            Some((&tag, _)) => Err(InvalidOptionTag { tag }.into()),
+            None => Err(SizeMismatch { expected: 1, found: 0 }.into()),
+        }
+    }
+}
+
+unsafe impl<C> Send for OptionCodec<C> {}
+
+unsafe impl<C> Sync for OptionCodec<C> {}
+
+/// The error returned when the first byte of an [`OptionCodec`] value is
+/// neither the tag of `None` nor the one of `Some`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidOptionTag {
+    /// The tag byte that was found.
+    pub tag: u8,
+}
+
+impl fmt::Display for InvalidOptionTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid option tag {}, expected {NONE_TAG} or {SOME_TAG}", self.tag)
+    }
+}
+
+impl error::Error for InvalidOptionTag {}
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use bytemuck::{bytes_of, AnyBitPattern, NoUninit};
 use heed_traits::{BoxedError, BytesDecode, BytesEncode};
 
-use crate::CowType;
+use crate::{CowType, FixedSize};
 
 /// Describes a type that is totally owned (doesn't
 /// hold any reference to the original slice).
@@ -42,6 +42,10 @@ impl<'a, T: AnyBitPattern + NoUninit> BytesDecode<'a> for OwnedType<T> {
     }
 }
 
+impl<T> FixedSize for OwnedType<T> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
 unsafe impl<T> Send for OwnedType<T> {}
 
 unsafe impl<T> Sync for OwnedType<T> {}
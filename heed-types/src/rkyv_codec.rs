@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+use rkyv::api::high::{HighSerializer, HighValidator};
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor;
+use rkyv::ser::allocator::ArenaHandle;
+use rkyv::util::AlignedVec;
+use rkyv::{Archive, Portable, Serialize};
+
+/// Describes a type that is [`Archive`]d with `rkyv` and read without any copy.
+///
+/// Decoding validates the bytes and returns a reference to the archived type that
+/// borrows the original slice, its fields are directly read from the memory map.
+///
+/// LMDB doesn't align the values it stores, decoding returns an error when the bytes are
+/// not aligned as the archived type requires. The opt-in `rkyv-unaligned` feature enables
+/// the `unaligned` feature of `rkyv` which makes the archived types independent of any
+/// memory alignment. Note that it changes the archive format for every user of `rkyv`
+/// in the dependency graph, the values written with and without it are not compatible.
+pub struct RkyvCodec<T>(std::marker::PhantomData<T>);
+
+impl<'a, T: 'a> BytesEncode<'a> for RkyvCodec<T>
+where
+    T: for<'b> Serialize<HighSerializer<AlignedVec, ArenaHandle<'b>, rancor::Error>>,
+{
+    type EItem = T;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        rkyv::to_bytes::<rancor::Error>(item)
+            .map(|bytes| Cow::Owned(bytes.into_vec()))
+            .map_err(Into::into)
+    }
+}
+
+impl<'a, T: 'a> BytesDecode<'a> for RkyvCodec<T>
+where
+    T: Archive,
+    T::Archived: Portable + for<'b> CheckBytes<HighValidator<'b, rancor::Error>>,
+{
+    type DItem = &'a T::Archived;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        rkyv::access::<T::Archived, rancor::Error>(bytes).map_err(Into::into)
+    }
+}
+
+unsafe impl<T> Send for RkyvCodec<T> {}
+
+unsafe impl<T> Sync for RkyvCodec<T> {}
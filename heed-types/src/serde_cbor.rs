@@ -0,0 +1,39 @@
+use std::borrow::Cow;
+
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Describes a type that is [`Serialize`]/[`DeserializeOwned`] and uses `ciborium` to do so.
+///
+/// The data is stored as CBOR which makes it readable by non-Rust programs.
+/// It always allocates when decoding as `ciborium` can't borrow from the original slice.
+pub struct SerdeCbor<T>(std::marker::PhantomData<T>);
+
+impl<'a, T: 'a> BytesEncode<'a> for SerdeCbor<T>
+where
+    T: Serialize,
+{
+    type EItem = T;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(item, &mut bytes)?;
+        Ok(Cow::Owned(bytes))
+    }
+}
+
+impl<'a, T: 'a> BytesDecode<'a> for SerdeCbor<T>
+where
+    T: DeserializeOwned,
+{
+    type DItem = T;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        ciborium::from_reader(bytes).map_err(Into::into)
+    }
+}
+
+unsafe impl<T> Send for SerdeCbor<T> {}
+
+unsafe impl<T> Sync for SerdeCbor<T> {}
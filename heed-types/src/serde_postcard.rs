@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+use serde::{Deserialize, Serialize};
+
+/// Describes a type that is [`Serialize`]/[`Deserialize`] and uses `postcard` to do so.
+///
+/// The data is stored in the compact and stable postcard wire format.
+/// It can borrow bytes from the original slice.
+pub struct SerdePostcard<T>(std::marker::PhantomData<T>);
+
+impl<'a, T: 'a> BytesEncode<'a> for SerdePostcard<T>
+where
+    T: Serialize,
+{
+    type EItem = T;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        postcard::to_allocvec(item).map(Cow::Owned).map_err(Into::into)
+    }
+}
+
+impl<'a, T: 'a> BytesDecode<'a> for SerdePostcard<T>
+where
+    T: Deserialize<'a>,
+{
+    type DItem = T;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        postcard::from_bytes(bytes).map_err(Into::into)
+    }
+}
+
+unsafe impl<T> Send for SerdePostcard<T> {}
+
+unsafe impl<T> Sync for SerdePostcard<T> {}
@@ -1,8 +1,12 @@
 use std::borrow::Cow;
+use std::marker::PhantomData;
 
 use heed_traits::{BoxedError, BytesDecode, BytesEncode};
 
 /// Describes an [`prim@str`].
+///
+/// The bytes are stored as is, decoding borrows them back as the exact same `&str`
+/// after checking they are valid UTF-8.
 pub struct Str;
 
 impl BytesEncode<'_> for Str {
@@ -20,3 +24,62 @@ impl<'a> BytesDecode<'a> for Str {
         std::str::from_utf8(bytes).map_err(Into::into)
     }
 }
+
+/// Vouches that all the bytes decoded by a [`StrUnchecked`] are valid UTF-8.
+///
+/// It is implemented on a marker type of your own, e.g. one per database, which is
+/// then given to [`StrUnchecked`] to opt into the unchecked decoding.
+///
+/// # Safety
+///
+/// Every bytes decoded with `StrUnchecked<Self>` must be valid UTF-8, decoding other
+/// bytes is _[undefined behavior]_. Only implement it for databases that you know were
+/// exclusively written with valid strings, i.e. with [`Str`] or [`StrUnchecked`].
+///
+/// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+pub unsafe trait TrustedUtf8 {}
+
+/// Describes an [`prim@str`] that is decoded without checking that it is valid UTF-8.
+///
+/// It is encoded just like [`Str`] so both types can be used on the same database,
+/// but decoding skips the UTF-8 validation, which can matter for read-heavy workloads.
+/// The marker `T` must implement the unsafe [`TrustedUtf8`] trait, the validation is
+/// still done in debug builds. Prefer [`Str`] when in doubt.
+///
+/// ```
+/// use heed_types::{StrUnchecked, TrustedUtf8};
+/// use heed_traits::{BytesDecode, BytesEncode};
+///
+/// enum UserNames {}
+///
+/// // safety: the user names are only ever written with `Str` or `StrUnchecked`.
+/// unsafe impl TrustedUtf8 for UserNames {}
+///
+/// type UserName = StrUnchecked<UserNames>;
+///
+/// let bytes = UserName::bytes_encode("kerollmops").unwrap();
+/// assert_eq!(UserName::bytes_decode(&bytes).unwrap(), "kerollmops");
+/// ```
+pub struct StrUnchecked<T>(PhantomData<T>);
+
+impl<'a, T> BytesEncode<'a> for StrUnchecked<T> {
+    type EItem = str;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        Str::bytes_encode(item)
+    }
+}
+
+impl<'a, T: TrustedUtf8> BytesDecode<'a> for StrUnchecked<T> {
+    type DItem = &'a str;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        debug_assert!(std::str::from_utf8(bytes).is_ok(), "StrUnchecked decoded invalid UTF-8");
+        // safety: the implementer of TrustedUtf8 promised that the bytes are valid UTF-8.
+        Ok(unsafe { std::str::from_utf8_unchecked(bytes) })
+    }
+}
+
+unsafe impl<T> Send for StrUnchecked<T> {}
+
+unsafe impl<T> Sync for StrUnchecked<T> {}
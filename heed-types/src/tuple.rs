@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+
+use crate::SizeMismatch;
+
+/// A codec that always encodes its values in the same number of bytes.
+///
+/// It is required for all the components of a [`Tuple2`] or a [`Tuple3`] but the last one,
+/// the decoder must be able to tell where the component ends. It also lets heed split
+/// the pages of values it reads in bulk from a `DUP_FIXED` database.
+pub trait FixedSize {
+    /// The number of bytes of every encoded value.
+    const SIZE: usize;
+}
+
+/// Appends the bytes of a fixed size component to the buffer.
+fn encode_fixed<'a, C>(buffer: &mut Vec<u8>, item: &'a C::EItem) -> Result<(), BoxedError>
+where
+    C: FixedSize + BytesEncode<'a>,
+{
+    let bytes = C::bytes_encode(item)?;
+    SizeMismatch::check(C::SIZE, &bytes)?;
+    buffer.extend_from_slice(&bytes);
+    Ok(())
+}
+
+/// Decodes the fixed size component at the start of the bytes, returns it with the remaining bytes.
+fn decode_fixed<'a, C>(bytes: &'a [u8]) -> Result<(C::DItem, &'a [u8]), BoxedError>
+where
+    C: FixedSize + BytesDecode<'a>,
+{
+    if bytes.len() < C::SIZE {
+        return Err(SizeMismatch { expected: C::SIZE, found: bytes.len() }.into());
+    }
+    let (head, tail) = bytes.split_at(C::SIZE);
+    Ok((C::bytes_decode(head)?, tail))
+}
+
+/// Describes a pair of values that are encoded one after the other, e.g. to build composite keys.
+///
+/// The first component must be [`FixedSize`], the second one can be of any length.
+/// The keys are ordered by the first component then by the second one as long as the
+/// components themselves are ordered byte-wise, e.g. with big-endian integers, this way
+/// a prefix or range iteration on the first component returns all its entries.
+///
+/// ```
+/// use heed_types::{Str, Tuple2, BEU32};
+/// use heed_traits::{BytesDecode, BytesEncode};
+///
+/// type UserPost = Tuple2<BEU32, Str>;
+///
+/// let bytes = UserPost::bytes_encode(&(&42, "hello")).unwrap();
+/// assert_eq!(&bytes[..], &[0, 0, 0, 42, b'h', b'e', b'l', b'l', b'o']);
+/// assert_eq!(UserPost::bytes_decode(&bytes).unwrap(), (42, "hello"));
+/// ```
+pub struct Tuple2<A, B>(PhantomData<(A, B)>);
+
+impl<'a, A, B> BytesEncode<'a> for Tuple2<A, B>
+where
+    A: FixedSize + BytesEncode<'a>,
+    B: BytesEncode<'a>,
+{
+    type EItem = (&'a A::EItem, &'a B::EItem);
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        let (a, b) = *item;
+        let last = B::bytes_encode(b)?;
+        let mut buffer = Vec::with_capacity(A::SIZE + last.len());
+        encode_fixed::<A>(&mut buffer, a)?;
+        buffer.extend_from_slice(&last);
+        Ok(Cow::Owned(buffer))
+    }
+}
+
+impl<'a, A, B> BytesDecode<'a> for Tuple2<A, B>
+where
+    A: FixedSize + BytesDecode<'a>,
+    B: BytesDecode<'a>,
+{
+    type DItem = (A::DItem, B::DItem);
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        let (a, bytes) = decode_fixed::<A>(bytes)?;
+        Ok((a, B::bytes_decode(bytes)?))
+    }
+}
+
+unsafe impl<A, B> Send for Tuple2<A, B> {}
+
+unsafe impl<A, B> Sync for Tuple2<A, B> {}
+
+/// Describes a triple of values that are encoded one after the other, e.g. to build composite keys.
+///
+/// The first two components must be [`FixedSize`], the last one can be of any length.
+/// Like with [`Tuple2`] the keys are ordered component by component.
+///
+/// ```
+/// use heed_types::{Str, Tuple3, BEU32, BEU64};
+/// use heed_traits::{BytesDecode, BytesEncode};
+///
+/// type Event = Tuple3<BEU32, BEU64, Str>;
+///
+/// let bytes = Event::bytes_encode(&(&1, &1_700_000_000, "login")).unwrap();
+/// assert_eq!(bytes.len(), 4 + 8 + 5);
+/// assert_eq!(Event::bytes_decode(&bytes).unwrap(), (1, 1_700_000_000, "login"));
+/// ```
+pub struct Tuple3<A, B, C>(PhantomData<(A, B, C)>);
+
+impl<'a, A, B, C> BytesEncode<'a> for Tuple3<A, B, C>
+where
+    A: FixedSize + BytesEncode<'a>,
+    B: FixedSize + BytesEncode<'a>,
+    C: BytesEncode<'a>,
+{
+    type EItem = (&'a A::EItem, &'a B::EItem, &'a C::EItem);
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        let (a, b, c) = *item;
+        let last = C::bytes_encode(c)?;
+        let mut buffer = Vec::with_capacity(A::SIZE + B::SIZE + last.len());
+        encode_fixed::<A>(&mut buffer, a)?;
+        encode_fixed::<B>(&mut buffer, b)?;
+        buffer.extend_from_slice(&last);
+        Ok(Cow::Owned(buffer))
+    }
+}
+
+impl<'a, A, B, C> BytesDecode<'a> for Tuple3<A, B, C>
+where
+    A: FixedSize + BytesDecode<'a>,
+    B: FixedSize + BytesDecode<'a>,
+    C: BytesDecode<'a>,
+{
+    type DItem = (A::DItem, B::DItem, C::DItem);
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        let (a, bytes) = decode_fixed::<A>(bytes)?;
+        let (b, bytes) = decode_fixed::<B>(bytes)?;
+        Ok((a, b, C::bytes_decode(bytes)?))
+    }
+}
+
+unsafe impl<A, B, C> Send for Tuple3<A, B, C> {}
+
+unsafe impl<A, B, C> Sync for Tuple3<A, B, C> {}
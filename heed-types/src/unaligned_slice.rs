@@ -1,8 +1,11 @@
 use std::borrow::Cow;
+use std::mem::size_of;
 
 use bytemuck::{try_cast_slice, AnyBitPattern, NoUninit};
 use heed_traits::{BoxedError, BytesDecode, BytesEncode};
 
+use crate::SizeMismatch;
+
 /// Describes a type that is totally borrowed and doesn't
 /// depends on any [memory alignment].
 ///
@@ -25,6 +28,7 @@ impl<'a, T: AnyBitPattern> BytesDecode<'a> for UnalignedSlice<T> {
     type DItem = &'a [T];
 
     fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        SizeMismatch::check_multiple(size_of::<T>(), bytes)?;
         try_cast_slice(bytes).map_err(Into::into)
     }
 }
@@ -1,8 +1,11 @@
 use std::borrow::Cow;
+use std::mem::size_of;
 
 use bytemuck::{bytes_of, try_from_bytes, AnyBitPattern, NoUninit};
 use heed_traits::{BoxedError, BytesDecode, BytesEncode};
 
+use crate::{FixedSize, SizeMismatch};
+
 /// Describes a slice that is totally borrowed and doesn't
 /// depends on any [memory alignment].
 ///
@@ -31,10 +34,15 @@ impl<'a, T: AnyBitPattern> BytesDecode<'a> for UnalignedType<T> {
     type DItem = &'a T;
 
     fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        SizeMismatch::check(size_of::<T>(), bytes)?;
         try_from_bytes(bytes).map_err(Into::into)
     }
 }
 
+impl<T> FixedSize for UnalignedType<T> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
 unsafe impl<T> Send for UnalignedType<T> {}
 
 unsafe impl<T> Sync for UnalignedType<T> {}
@@ -3,6 +3,8 @@ use std::borrow::Cow;
 use bytemuck::PodCastError;
 use heed_traits::{BoxedError, BytesDecode, BytesEncode};
 
+use crate::FixedSize;
+
 /// Describes the `()` type.
 pub struct Unit;
 
@@ -25,3 +27,7 @@ impl BytesDecode<'_> for Unit {
         }
     }
 }
+
+impl FixedSize for Unit {
+    const SIZE: usize = 0;
+}
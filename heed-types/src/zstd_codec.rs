@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+
+/// Describes a codec that compresses the bytes of another codec with zstd.
+///
+/// The bytes produced by the inner codec `C` are compressed at the given `LEVEL`,
+/// they are decompressed into a new buffer before being decoded by it,
+/// the decoded type must therefore not borrow from the bytes.
+pub struct ZstdCodec<C, const LEVEL: i32>(std::marker::PhantomData<C>);
+
+impl<'a, C, const LEVEL: i32> BytesEncode<'a> for ZstdCodec<C, LEVEL>
+where
+    C: BytesEncode<'a>,
+{
+    type EItem = C::EItem;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        let bytes = C::bytes_encode(item)?;
+        zstd::bulk::compress(&bytes, LEVEL).map(Cow::Owned).map_err(Into::into)
+    }
+}
+
+impl<'a, C, T: 'a, const LEVEL: i32> BytesDecode<'a> for ZstdCodec<C, LEVEL>
+where
+    C: for<'b> BytesDecode<'b, DItem = T>,
+{
+    type DItem = T;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        let bytes = zstd::stream::decode_all(bytes)?;
+        C::bytes_decode(&bytes)
+    }
+}
+
+unsafe impl<C, const LEVEL: i32> Send for ZstdCodec<C, LEVEL> {}
+
+unsafe impl<C, const LEVEL: i32> Sync for ZstdCodec<C, LEVEL> {}
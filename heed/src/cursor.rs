@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::ops::{Deref, DerefMut};
 use std::{marker, mem, ptr};
 
@@ -94,6 +95,26 @@ impl<'txn> RoCursor<'txn> {
         }
     }
 
+    pub fn move_on_key(&mut self, key: &[u8]) -> Result<bool> {
+        let mut key_val = unsafe { crate::into_val(&key) };
+
+        // Move the cursor to the specified key
+        let result = unsafe {
+            mdb_result(ffi::mdb_cursor_get(
+                self.cursor,
+                &mut key_val,
+                &mut ffi::MDB_val { mv_size: 0, mv_data: ptr::null_mut() },
+                ffi::cursor_op::MDB_SET,
+            ))
+        };
+
+        match result {
+            Ok(()) => Ok(true),
+            Err(e) if e.not_found() => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub fn move_on_key_greater_than_or_equal_to(
         &mut self,
         key: &[u8],
@@ -171,6 +192,121 @@ impl<'txn> RoCursor<'txn> {
             Err(e) => Err(e.into()),
         }
     }
+
+    pub fn move_on_next_dup(&mut self) -> Result<Option<(&'txn [u8], &'txn [u8])>> {
+        let mut key_val = mem::MaybeUninit::uninit();
+        let mut data_val = mem::MaybeUninit::uninit();
+
+        // Move the cursor to the next duplicate data of the current key
+        let result = unsafe {
+            mdb_result(ffi::mdb_cursor_get(
+                self.cursor,
+                key_val.as_mut_ptr(),
+                data_val.as_mut_ptr(),
+                ffi::cursor_op::MDB_NEXT_DUP,
+            ))
+        };
+
+        match result {
+            Ok(()) => {
+                let key = unsafe { crate::from_val(key_val.assume_init()) };
+                let data = unsafe { crate::from_val(data_val.assume_init()) };
+                Ok(Some((key, data)))
+            }
+            Err(e) if e.not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn get_multiple(&mut self) -> Result<Option<&'txn [u8]>> {
+        self.get_multiple_with_op(ffi::cursor_op::MDB_GET_MULTIPLE)
+    }
+
+    pub fn move_on_next_multiple(&mut self) -> Result<Option<&'txn [u8]>> {
+        self.get_multiple_with_op(ffi::cursor_op::MDB_NEXT_MULTIPLE)
+    }
+
+    // Returns up to a page of the duplicate data of the current key,
+    // only works on databases created with the `DUP_FIXED` flag.
+    //
+    // LMDB succeeds without touching the data when the key holds a single
+    // value stored inline, we return `None` in this case too.
+    fn get_multiple_with_op(&mut self, op: ffi::MDB_cursor_op) -> Result<Option<&'txn [u8]>> {
+        let mut key_val = mem::MaybeUninit::uninit();
+        let mut data_val = ffi::MDB_val { mv_size: 0, mv_data: ptr::null_mut() };
+
+        let result = unsafe {
+            mdb_result(ffi::mdb_cursor_get(self.cursor, key_val.as_mut_ptr(), &mut data_val, op))
+        };
+
+        match result {
+            Ok(()) if data_val.mv_data.is_null() => Ok(None),
+            Ok(()) => {
+                let data = unsafe { crate::from_val(data_val) };
+                Ok(Some(data))
+            }
+            Err(e) if e.not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn move_on_next_nodup(&mut self) -> Result<Option<(&'txn [u8], &'txn [u8])>> {
+        let mut key_val = mem::MaybeUninit::uninit();
+        let mut data_val = mem::MaybeUninit::uninit();
+
+        // Move the cursor to the next key, skipping the duplicate data of the current one
+        let result = unsafe {
+            mdb_result(ffi::mdb_cursor_get(
+                self.cursor,
+                key_val.as_mut_ptr(),
+                data_val.as_mut_ptr(),
+                ffi::cursor_op::MDB_NEXT_NODUP,
+            ))
+        };
+
+        match result {
+            Ok(()) => {
+                let key = unsafe { crate::from_val(key_val.assume_init()) };
+                let data = unsafe { crate::from_val(data_val.assume_init()) };
+                Ok(Some((key, data)))
+            }
+            Err(e) if e.not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Compares two keys with the comparison function of the database of this cursor.
+    pub fn compare_keys(&self, a: &[u8], b: &[u8]) -> Ordering {
+        let a = unsafe { crate::into_val(a) };
+        let b = unsafe { crate::into_val(b) };
+        let result = unsafe {
+            let txn = ffi::mdb_cursor_txn(self.cursor);
+            let dbi = ffi::mdb_cursor_dbi(self.cursor);
+            ffi::mdb_cmp(txn, dbi, &a, &b)
+        };
+        result.cmp(&0)
+    }
+
+    pub fn move_on_last_dup(&mut self) -> Result<Option<(&'txn [u8], &'txn [u8])>> {
+        let mut data_val = mem::MaybeUninit::uninit();
+
+        // Move the cursor to the last duplicate data of the current key,
+        // LMDB doesn't return the key with this operation.
+        let result = unsafe {
+            mdb_result(ffi::mdb_cursor_get(
+                self.cursor,
+                ptr::null_mut(),
+                data_val.as_mut_ptr(),
+                ffi::cursor_op::MDB_LAST_DUP,
+            ))
+        };
+
+        match result {
+            Ok(()) => self.current(),
+            Err(e) if e.not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
 
 impl Drop for RoCursor<'_> {
@@ -181,11 +317,12 @@ impl Drop for RoCursor<'_> {
 
 pub struct RwCursor<'txn> {
     cursor: RoCursor<'txn>,
+    env: &'txn Env,
 }
 
 impl<'txn> RwCursor<'txn> {
     pub(crate) fn new(txn: &'txn RwTxn, dbi: ffi::MDB_dbi) -> Result<RwCursor<'txn>> {
-        Ok(RwCursor { cursor: RoCursor::new(txn, dbi)? })
+        Ok(RwCursor { cursor: RoCursor::new(txn, dbi)?, env: txn.env() })
     }
 
     /// Delete the entry the cursor is currently pointing to.
@@ -208,7 +345,7 @@ impl<'txn> RwCursor<'txn> {
         match result {
             Ok(()) => Ok(true),
             Err(e) if e.not_found() => Ok(false),
-            Err(e) => Err(e.into()),
+            Err(e) => Err(self.env.write_error(e)),
         }
     }
 
@@ -250,7 +387,7 @@ impl<'txn> RwCursor<'txn> {
         match result {
             Ok(()) => Ok(true),
             Err(e) if e.not_found() => Ok(false),
-            Err(e) => Err(e.into()),
+            Err(e) => Err(self.env.write_error(e)),
         }
     }
 
@@ -286,7 +423,7 @@ impl<'txn> RwCursor<'txn> {
         let found = match result {
             Ok(()) => true,
             Err(e) if e.not_found() => false,
-            Err(e) => return Err(e.into()),
+            Err(e) => return Err(self.env.write_error(e)),
         };
 
         let mut reserved = ReservedSpace::from_val(reserved);
@@ -329,7 +466,30 @@ impl<'txn> RwCursor<'txn> {
             ffi::MDB_APPEND,
         ));
 
-        result.map_err(Into::into)
+        result.map_err(|e| self.env.write_error(e))
+    }
+
+    /// Write the given key/data pair at the position LMDB chooses for it, with the given flags.
+    ///
+    /// # Safety
+    ///
+    /// Please read the safety notes of the `[put_current]` method.
+    pub(crate) unsafe fn put_with_flags(
+        &mut self,
+        key: &[u8],
+        data: &[u8],
+        flags: PutFlags,
+    ) -> Result<()> {
+        let mut key_val = crate::into_val(&key);
+        let mut data_val = crate::into_val(&data);
+
+        mdb_result(ffi::mdb_cursor_put(
+            self.cursor.cursor,
+            &mut key_val,
+            &mut data_val,
+            flags.bits(),
+        ))
+        .map_err(|e| self.env.write_error(e))
     }
 }
 
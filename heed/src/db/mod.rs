@@ -1,5 +1,23 @@
 mod polymorph;
 mod uniform;
 
+use byteorder::NativeEndian;
+
+use crate::types::{U32, U64};
+
 pub use self::polymorph::PolyDatabase;
 pub use self::uniform::Database;
+
+/// A key codec that can be used with a database created with
+/// the [`DatabaseFlags::INTEGER_KEY`](crate::DatabaseFlags::INTEGER_KEY) flag.
+///
+/// # Safety
+///
+/// The codec must encode the keys as native endian integers of the size
+/// of an `u32` or of an `usize`, the same size for every key.
+pub unsafe trait IntegerKey {}
+
+unsafe impl IntegerKey for U32<NativeEndian> {}
+
+#[cfg(target_pointer_width = "64")]
+unsafe impl IntegerKey for U64<NativeEndian> {}
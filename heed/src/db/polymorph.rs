@@ -1,12 +1,17 @@
-use std::borrow::Cow;
+use std::any::TypeId;
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
 use std::ops::{Bound, RangeBounds};
 use std::{fmt, mem, ptr};
 
 use crate::mdb::error::mdb_result;
 use crate::mdb::ffi;
-use crate::types::DecodeIgnore;
+use crate::types::{Bytes, DecodeIgnore};
 use crate::*;
 
+/// The dbi of the unnamed database, LMDB never closes its handle.
+const MAIN_DBI: u32 = 1;
+
 /// A polymorphic database that accepts types on call methods and not at creation.
 ///
 /// # Example: Iterate over ranges of databases entries
@@ -180,6 +185,216 @@ impl PolyDatabase {
         }
     }
 
+    /// Retrieves the value associated with a key or the [`Default`] value of the data type
+    /// if the key does not exist.
+    ///
+    /// Prefer [`Self::get`] when the absence of the key must be distinguished from a default value.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("get-or-default-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<Str, BEI32>(&mut wtxn, "visits", &42)?;
+    ///
+    /// let ret = db.get_or_default::<Str, BEI32>(&wtxn, "visits")?;
+    /// assert_eq!(ret, 42);
+    ///
+    /// let ret = db.get_or_default::<Str, BEI32>(&wtxn, "downloads")?;
+    /// assert_eq!(ret, 0);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_or_default<'a, 'txn, KC, DC>(
+        &self,
+        txn: &'txn RoTxn,
+        key: &'a KC::EItem,
+    ) -> Result<DC::DItem>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesDecode<'txn>,
+        DC::DItem: Default,
+    {
+        self.get::<KC, DC>(txn, key).map(Option::unwrap_or_default)
+    }
+
+    /// Returns `true` if the key exists in this database, the value is never decoded.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("exists-poly-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<Str, BEI32>(&mut wtxn, "i-am-forty-two", &42)?;
+    /// db.put::<Str, BEI32>(&mut wtxn, "i-am-twenty-seven", &27)?;
+    ///
+    /// assert!(db.exists::<Str>(&wtxn, "i-am-forty-two")?);
+    /// assert!(!db.exists::<Str>(&wtxn, "i-am-twenty-one")?);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn exists<'a, KC>(&self, txn: &RoTxn, key: &'a KC::EItem) -> Result<bool>
+    where
+        KC: BytesEncode<'a>,
+    {
+        self.get::<KC, DecodeIgnore>(txn, key).map(|data| data.is_some())
+    }
+
+    /// Retrieves all the duplicate values of a key in a database created with the
+    /// [`DatabaseFlags::DUP_SORT`] flag, returns `None` if the key doesn't exist.
+    ///
+    /// The values are returned in the sorted order they are stored in.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{DatabaseFlags, PolyDatabase};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let flags = DatabaseFlags::DUP_SORT;
+    /// let db = env.create_poly_database_with_flags(&mut wtxn, Some("dup-i32"), flags)?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &68, "i-am-sixty-eight")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &35, "i-am-thirty-five")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &35, "i-am-also-thirty-five")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &0, "i-am-zero")?;
+    ///
+    /// let mut iter = db.get_duplicates::<BEI32, Str>(&wtxn, &35)?.unwrap();
+    /// assert_eq!(iter.next().transpose()?, Some((35, "i-am-also-thirty-five")));
+    /// assert_eq!(iter.next().transpose()?, Some((35, "i-am-thirty-five")));
+    /// assert_eq!(iter.next().transpose()?, None);
+    /// drop(iter);
+    ///
+    /// assert!(db.get_duplicates::<BEI32, Str>(&wtxn, &42)?.is_none());
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_duplicates<'a, 'txn, KC, DC>(
+        &self,
+        txn: &'txn RoTxn,
+        key: &'a KC::EItem,
+    ) -> Result<Option<RoIter<'txn, KC, DC>>>
+    where
+        KC: BytesEncode<'a>,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
+        let mut cursor = RoCursor::new(txn, self.dbi)?;
+        if cursor.move_on_key(&key_bytes)? {
+            Ok(Some(RoIter::new_duplicates(cursor)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Retrieves all the duplicate values of a key in a database created with both the
+    /// [`DatabaseFlags::DUP_SORT`] and [`DatabaseFlags::DUP_FIXED`] flags,
+    /// returns `None` if the key doesn't exist.
+    ///
+    /// Unlike [`PolyDatabase::get_duplicates`], the values are read from LMDB a whole page
+    /// at a time and split according to the [`FixedSize`](crate::types::FixedSize) of the
+    /// data codec, which is much faster when a key holds a lot of small values.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{DatabaseFlags, PolyDatabase};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    /// type BEU64 = U64<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let flags = DatabaseFlags::DUP_SORT | DatabaseFlags::DUP_FIXED;
+    /// let db = env.create_poly_database_with_flags(&mut wtxn, Some("dup-fixed-u64"), flags)?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, BEU64>(&mut wtxn, &68, &6800)?;
+    /// db.put::<BEI32, BEU64>(&mut wtxn, &35, &3501)?;
+    /// db.put::<BEI32, BEU64>(&mut wtxn, &35, &3500)?;
+    /// db.put::<BEI32, BEU64>(&mut wtxn, &0, &0)?;
+    ///
+    /// let iter = db.get_duplicates_bulk::<BEI32, BEU64>(&wtxn, &35)?.unwrap();
+    /// assert_eq!(iter.collect::<heed::Result<Vec<_>>>()?, vec![3500, 3501]);
+    ///
+    /// assert!(db.get_duplicates_bulk::<BEI32, BEU64>(&wtxn, &42)?.is_none());
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_duplicates_bulk<'a, 'txn, KC, DC>(
+        &self,
+        txn: &'txn RoTxn,
+        key: &'a KC::EItem,
+    ) -> Result<Option<RoBulkDuplicates<'txn, DC>>>
+    where
+        KC: BytesEncode<'a>,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
+        let mut cursor = RoCursor::new(txn, self.dbi)?;
+        if cursor.move_on_key(&key_bytes)? {
+            Ok(Some(RoBulkDuplicates::new(cursor)))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Retrieves the key/value pair lower than the given one in this database.
     ///
     /// If the database if empty or there is no key lower than the given one,
@@ -305,7 +520,9 @@ impl PolyDatabase {
         let mut cursor = RoCursor::new(txn, self.dbi)?;
         let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
         let result = match cursor.move_on_key_greater_than_or_equal_to(&key_bytes) {
-            Ok(Some((key, data))) if key == &key_bytes[..] => Ok(Some((key, data))),
+            Ok(Some((key, data))) if cursor.compare_keys(key, &key_bytes).is_eq() => {
+                Ok(Some((key, data)))
+            }
             Ok(_) => cursor.move_on_prev(),
             Err(e) => Err(e),
         };
@@ -377,9 +594,12 @@ impl PolyDatabase {
         let mut cursor = RoCursor::new(txn, self.dbi)?;
         let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
         let entry = match cursor.move_on_key_greater_than_or_equal_to(&key_bytes)? {
-            Some((key, data)) if key > &key_bytes[..] => Some((key, data)),
-            Some((_key, _data)) => cursor.move_on_next()?,
-            None => None,
+            // the keys are ordered by the comparator of the database, which
+            // can consider keys with different bytes equal to the given one
+            Some((key, _)) if cursor.compare_keys(key, &key_bytes).is_eq() => {
+                cursor.move_on_next_nodup()?
+            }
+            entry => entry,
         };
 
         match entry {
@@ -459,7 +679,7 @@ impl PolyDatabase {
 
     /// Retrieves the first key/value pair of this database.
     ///
-    /// If the database if empty, then `None` is returned.
+    /// If the database is empty, then `None` is returned.
     ///
     /// Comparisons are made by using the bytes representation of the key.
     ///
@@ -483,6 +703,9 @@ impl PolyDatabase {
     /// let db = env.create_poly_database(&mut wtxn, Some("first-poly-i32"))?;
     ///
     /// # db.clear(&mut wtxn)?;
+    /// let ret = db.first::<BEI32, Str>(&wtxn)?;
+    /// assert_eq!(ret, None);
+    ///
     /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
     /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
     ///
@@ -512,7 +735,7 @@ impl PolyDatabase {
 
     /// Retrieves the last key/value pair of this database.
     ///
-    /// If the database if empty, then `None` is returned.
+    /// If the database is empty, then `None` is returned.
     ///
     /// Comparisons are made by using the bytes representation of the key.
     ///
@@ -536,6 +759,9 @@ impl PolyDatabase {
     /// let db = env.create_poly_database(&mut wtxn, Some("last-i32"))?;
     ///
     /// # db.clear(&mut wtxn)?;
+    /// let ret = db.last::<BEI32, Str>(&wtxn)?;
+    /// assert_eq!(ret, None);
+    ///
     /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
     /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
     ///
@@ -565,6 +791,11 @@ impl PolyDatabase {
 
     /// Returns the number of elements in this database.
     ///
+    /// The count is read from the database statistics of the transaction snapshot,
+    /// it doesn't iterate over the entries. For a database opened with the
+    /// [`DatabaseFlags::DUP_SORT`] flag every duplicate value is counted, not only
+    /// the distinct keys.
+    ///
     /// ```
     /// # use std::fs;
     /// # use std::path::Path;
@@ -619,6 +850,8 @@ impl PolyDatabase {
 
     /// Returns `true` if and only if this database is empty.
     ///
+    /// This is equivalent to checking that [`Self::len`] returns zero.
+    ///
     /// ```
     /// # use std::fs;
     /// # use std::path::Path;
@@ -656,13 +889,7 @@ impl PolyDatabase {
     /// # Ok(()) }
     /// ```
     pub fn is_empty<'txn>(&self, txn: &'txn RoTxn) -> Result<bool> {
-        assert_eq_env_db_txn!(self, txn);
-
-        let mut cursor = RoCursor::new(txn, self.dbi)?;
-        match cursor.move_on_first()? {
-            Some(_) => Ok(false),
-            None => Ok(true),
-        }
+        self.len(txn).map(|len| len == 0)
     }
 
     /// Return a lexicographically ordered iterator of all key-value pairs in this database.
@@ -707,6 +934,134 @@ impl PolyDatabase {
         RoCursor::new(txn, self.dbi).map(|cursor| RoIter::new(cursor))
     }
 
+    /// Return a lexicographically ordered iterator of the key-value pairs in this database
+    /// whose keys are strictly greater than the given one.
+    ///
+    /// This is the building block of keyset pagination: the last key of a page is enough
+    /// to resume the iteration in a fresh transaction. The given key doesn't need to exist,
+    /// if it was deleted between two pages the iteration resumes at the next existing key.
+    ///
+    /// If the database was created with the [`DatabaseFlags::DUP_SORT`] flag, all the
+    /// duplicate data of the given key are skipped.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("iter-from-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &13, "i-am-thirteen")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    /// wtxn.commit()?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// let page: Vec<_> = db.iter_from::<BEI32, Str>(&rtxn, &13)?.take(2).collect::<Result<_, _>>()?;
+    /// assert_eq!(page, [(27, "i-am-twenty-seven"), (42, "i-am-forty-two")]);
+    /// let (last_seen, _) = page[1];
+    /// drop(rtxn);
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// db.delete::<BEI32>(&mut wtxn, &last_seen)?;
+    /// wtxn.commit()?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// let mut iter = db.iter_from::<BEI32, Str>(&rtxn, &last_seen)?;
+    /// assert_eq!(iter.next().transpose()?, Some((521, "i-am-five-hundred-and-twenty-one")));
+    /// assert_eq!(iter.next().transpose()?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn iter_from<'a, 'txn, KC, DC>(
+        &self,
+        txn: &'txn RoTxn,
+        after: &'a KC::EItem,
+    ) -> Result<RoIter<'txn, KC, DC>>
+    where
+        KC: BytesEncode<'a>,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        let mut cursor = RoCursor::new(txn, self.dbi)?;
+        let key_bytes: Cow<[u8]> = KC::bytes_encode(after).map_err(Error::Encoding)?;
+        let start = match cursor.move_on_key_greater_than_or_equal_to(&key_bytes)? {
+            // the keys are ordered by the comparator of the database, which
+            // can consider keys with different bytes equal to the given one
+            Some((key, _)) if cursor.compare_keys(key, &key_bytes).is_eq() => {
+                cursor.move_on_next_nodup()?
+            }
+            entry => entry,
+        };
+
+        // The iterator moves on the next entry before returning it, we step back on the
+        // previous entry, or on the last one when there is nothing to return.
+        match start {
+            Some(_) => match cursor.move_on_prev()? {
+                Some(_) => Ok(RoIter::new_after_current(cursor)),
+                None => Ok(RoIter::new(cursor)),
+            },
+            None => {
+                cursor.move_on_last()?;
+                Ok(RoIter::new_after_current(cursor))
+            }
+        }
+    }
+
+    /// Return a lexicographically ordered iterator of all the raw key-value pairs in this database.
+    ///
+    /// The keys and data are yielded as the bytes stored in LMDB, no codec is involved.
+    /// This is useful to dump, copy or checksum a database without paying for the decoding.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("iter-raw-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &13, "i-am-thirteen")?;
+    ///
+    /// let mut iter = db.iter_raw(&wtxn)?;
+    /// assert_eq!(iter.next().transpose()?, Some((&[0, 0, 0, 13][..], &b"i-am-thirteen"[..])));
+    /// assert_eq!(iter.next().transpose()?, Some((&[0, 0, 0, 27][..], &b"i-am-twenty-seven"[..])));
+    /// assert_eq!(iter.next().transpose()?, None);
+    ///
+    /// drop(iter);
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn iter_raw<'txn>(&self, txn: &'txn RoTxn) -> Result<RoIterRaw<'txn>> {
+        self.iter::<Bytes, Bytes>(txn)
+    }
+
     /// Return a mutable lexicographically ordered iterator of all key-value pairs in this database.
     ///
     /// ```
@@ -865,7 +1220,8 @@ impl PolyDatabase {
 
     /// Return a lexicographically ordered iterator of a range of key-value pairs in this database.
     ///
-    /// Comparisons are made by using the bytes representation of the key.
+    /// Comparisons are made by using the bytes representation of the key with the comparator
+    /// of the database, see [`PolyDatabase::set_compare`], the bounds must follow its order.
     ///
     /// ```
     /// # use std::fs;
@@ -943,7 +1299,8 @@ impl PolyDatabase {
     /// Return a mutable lexicographically ordered iterator of a range of
     /// key-value pairs in this database.
     ///
-    /// Comparisons are made by using the bytes representation of the key.
+    /// Comparisons are made by using the bytes representation of the key with the comparator
+    /// of the database, see [`PolyDatabase::set_compare`], the bounds must follow its order.
     ///
     /// ```
     /// # use std::fs;
@@ -1034,7 +1391,8 @@ impl PolyDatabase {
     /// Return a reversed lexicographically ordered iterator of a range of key-value
     /// pairs in this database.
     ///
-    /// Comparisons are made by using the bytes representation of the key.
+    /// Comparisons are made by using the bytes representation of the key with the comparator
+    /// of the database, see [`PolyDatabase::set_compare`], the bounds must follow its order.
     ///
     /// ```
     /// # use std::fs;
@@ -1112,7 +1470,8 @@ impl PolyDatabase {
     /// Return a mutable reversed lexicographically ordered iterator of a range of
     /// key-value pairs in this database.
     ///
-    /// Comparisons are made by using the bytes representation of the key.
+    /// Comparisons are made by using the bytes representation of the key with the comparator
+    /// of the database, see [`PolyDatabase::set_compare`], the bounds must follow its order.
     ///
     /// ```
     /// # use std::fs;
@@ -1256,8 +1615,69 @@ impl PolyDatabase {
         RoCursor::new(txn, self.dbi).map(|cursor| RoPrefix::new(cursor, prefix_bytes))
     }
 
-    /// Return a mutable lexicographically ordered iterator of all key-value pairs
-    /// in this database that starts with the given prefix.
+    /// Return a lexicographically ordered iterator of all key-value pairs
+    /// in this database whose encoded key starts with the given bytes.
+    ///
+    /// Unlike [`Self::prefix_iter`], the prefix isn't encoded with the key codec. It is useful
+    /// with composite keys, to iterate over the entries sharing the same leading fields
+    /// without knowing the following ones. The prefix must be a valid leading portion of
+    /// the bytes produced by the key codec, the keys are still decoded with it.
+    ///
+    /// Comparisons are made by using the bytes representation of the key.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// // The keys are a big endian user id followed by a big endian timestamp.
+    /// fn key(user_id: u32, timestamp: u32) -> Vec<u8> {
+    ///     [user_id.to_be_bytes(), timestamp.to_be_bytes()].concat()
+    /// }
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("events"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<Bytes, Str>(&mut wtxn, &key(27, 1200), "logged-in")?;
+    /// db.put::<Bytes, Str>(&mut wtxn, &key(42, 1500), "logged-out")?;
+    /// db.put::<Bytes, Str>(&mut wtxn, &key(42, 1000), "logged-in")?;
+    /// db.put::<Bytes, Str>(&mut wtxn, &key(43, 900), "logged-in")?;
+    ///
+    /// let mut iter = db.prefix_iter_bytes::<Bytes, Str>(&wtxn, &42u32.to_be_bytes())?;
+    /// assert_eq!(iter.next().transpose()?, Some((&key(42, 1000)[..], "logged-in")));
+    /// assert_eq!(iter.next().transpose()?, Some((&key(42, 1500)[..], "logged-out")));
+    /// assert_eq!(iter.next().transpose()?, None);
+    ///
+    /// drop(iter);
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn prefix_iter_bytes<'txn, KC, DC>(
+        &self,
+        txn: &'txn RoTxn,
+        prefix: &[u8],
+    ) -> Result<RoPrefix<'txn, KC, DC>> {
+        assert_eq_env_db_txn!(self, txn);
+
+        let prefix_bytes = prefix.to_vec();
+        RoCursor::new(txn, self.dbi).map(|cursor| RoPrefix::new(cursor, prefix_bytes))
+    }
+
+    /// Return a mutable lexicographically ordered iterator of all key-value pairs
+    /// in this database that starts with the given prefix.
+    ///
+    /// The write transaction stays exclusively borrowed by the iterator until it is dropped,
+    /// the entries can be updated or deleted in the same cursor pass with
+    /// [`RwPrefix::put_current`] and [`RwPrefix::del_current`].
     ///
     /// Comparisons are made by using the bytes representation of the key.
     ///
@@ -1452,6 +1872,9 @@ impl PolyDatabase {
 
     /// Insert a key-value pair in this database.
     ///
+    /// If the database was created with the [`DatabaseFlags::DUP_SORT`] flag, the value
+    /// is added to the sorted duplicate values of the key instead of replacing them.
+    ///
     /// ```
     /// # use std::fs;
     /// # use std::path::Path;
@@ -1503,7 +1926,8 @@ impl PolyDatabase {
         let flags = 0;
 
         unsafe {
-            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut data_val, flags))?
+            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut data_val, flags))
+                .map_err(|e| txn.write_error(e))?
         }
 
         Ok(())
@@ -1562,7 +1986,8 @@ impl PolyDatabase {
         let flags = ffi::MDB_RESERVE;
 
         unsafe {
-            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut reserved, flags))?
+            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut reserved, flags))
+                .map_err(|e| txn.write_error(e))?
         }
 
         let mut reserved = unsafe { ReservedSpace::from_val(reserved) };
@@ -1574,6 +1999,72 @@ impl PolyDatabase {
         }
     }
 
+    /// Reserve space for a value of the given size and return it to be directly written.
+    ///
+    /// The returned slice points into the memory map and is only valid until the next
+    /// write operation on the transaction. Its bytes are uninitialized, every one of them
+    /// must be written before the value is read or the transaction committed.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// let value = "I am a long long long value";
+    /// let reserved = db.put_reserve::<BEI32>(&mut wtxn, &42, value.len())?;
+    /// for (byte, value_byte) in reserved.iter_mut().zip(value.as_bytes()) {
+    ///     byte.write(*value_byte);
+    /// }
+    ///
+    /// let ret = db.get::<BEI32, Str>(&mut wtxn, &42)?;
+    /// assert_eq!(ret, Some(value));
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn put_reserve<'a, 'txn, KC>(
+        &self,
+        txn: &'txn mut RwTxn,
+        key: &'a KC::EItem,
+        data_size: usize,
+    ) -> Result<&'txn mut [mem::MaybeUninit<u8>]>
+    where
+        KC: BytesEncode<'a>,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
+        let mut key_val = unsafe { crate::into_val(&key_bytes) };
+        let mut reserved = ffi::reserve_size_val(data_size);
+        let flags = ffi::MDB_RESERVE;
+
+        unsafe {
+            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut reserved, flags))
+                .map_err(|e| txn.write_error(e))?
+        }
+
+        // safety: LMDB gives us an uninitialized memory area, it is exposed as such and the
+        //         caller is the only one to initialize it. The mutable borrow of the
+        //         transaction prevents any other write operation that would invalidate it.
+        let ptr = reserved.mv_data as *mut mem::MaybeUninit<u8>;
+        Ok(unsafe { std::slice::from_raw_parts_mut(ptr, reserved.mv_size) })
+    }
+
     /// Append the given key/data pair to the end of the database.
     ///
     /// This option allows fast bulk loading when keys are already known to be in the correct order.
@@ -1630,21 +2121,20 @@ impl PolyDatabase {
         let flags = ffi::MDB_APPEND;
 
         unsafe {
-            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut data_val, flags))?
+            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut data_val, flags))
+                .map_err(|e| txn.write_error(e))?
         }
 
         Ok(())
     }
 
-    /// Deletes a key-value pairs in this database.
-    ///
-    /// If the key does not exist, then `false` is returned.
+    /// Insert a key-value pair in this database, with the given put flags.
     ///
     /// ```
     /// # use std::fs;
     /// # use std::path::Path;
     /// # use heed::EnvOpenOptions;
-    /// use heed::Database;
+    /// use heed::{Database, Error, MdbError, PutFlags};
     /// use heed::types::*;
     /// use heed::byteorder::BigEndian;
     ///
@@ -1657,54 +2147,59 @@ impl PolyDatabase {
     /// type BEI32 = I32<BigEndian>;
     ///
     /// let mut wtxn = env.write_txn()?;
-    /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("append-i32"))?;
     ///
     /// # db.clear(&mut wtxn)?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &13, "i-am-thirteen")?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    /// db.put_with_flags::<BEI32, Str>(&mut wtxn, &13, "i-am-thirteen", PutFlags::APPEND)?;
+    /// db.put_with_flags::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven", PutFlags::APPEND)?;
     ///
-    /// let ret = db.delete::<BEI32>(&mut wtxn, &27)?;
-    /// assert_eq!(ret, true);
+    /// let ret = db.put_with_flags::<BEI32, Str>(&mut wtxn, &21, "i-am-21", PutFlags::APPEND);
+    /// assert!(matches!(ret, Err(Error::Mdb(MdbError::KeyExist))));
     ///
-    /// let ret = db.get::<BEI32, Str>(&mut wtxn, &27)?;
-    /// assert_eq!(ret, None);
+    /// let ret = db.put_with_flags::<BEI32, Str>(&mut wtxn, &13, "i-am-13", PutFlags::NO_OVERWRITE);
+    /// assert!(matches!(ret, Err(Error::Mdb(MdbError::KeyExist))));
     ///
-    /// let ret = db.delete::<BEI32>(&mut wtxn, &467)?;
-    /// assert_eq!(ret, false);
+    /// let ret = db.get::<BEI32, Str>(&mut wtxn, &13)?;
+    /// assert_eq!(ret, Some("i-am-thirteen"));
     ///
     /// wtxn.commit()?;
     /// # Ok(()) }
     /// ```
-    pub fn delete<'a, KC>(&self, txn: &mut RwTxn, key: &'a KC::EItem) -> Result<bool>
+    pub fn put_with_flags<'a, KC, DC>(
+        &self,
+        txn: &mut RwTxn,
+        key: &'a KC::EItem,
+        data: &'a DC::EItem,
+        flags: PutFlags,
+    ) -> Result<()>
     where
         KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
     {
         assert_eq_env_db_txn!(self, txn);
 
         let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
-        let mut key_val = unsafe { crate::into_val(&key_bytes) };
+        let data_bytes: Cow<[u8]> = DC::bytes_encode(&data).map_err(Error::Encoding)?;
 
-        let result = unsafe {
-            mdb_result(ffi::mdb_del(txn.txn.txn, self.dbi, &mut key_val, ptr::null_mut()))
-        };
+        let mut key_val = unsafe { crate::into_val(&key_bytes) };
+        let mut data_val = unsafe { crate::into_val(&data_bytes) };
+        let flags = flags.bits();
 
-        match result {
-            Ok(()) => Ok(true),
-            Err(e) if e.not_found() => Ok(false),
-            Err(e) => Err(e.into()),
+        unsafe {
+            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut data_val, flags))
+                .map_err(|e| txn.write_error(e))?
         }
+
+        Ok(())
     }
 
-    /// Deletes a range of key-value pairs in this database.
+    /// Insert a key-value pair in this database only if the value differs from the stored one.
     ///
-    /// Perfer using [`clear`] instead of a call to this method with a full range ([`..`]).
-    ///
-    /// Comparisons are made by using the bytes representation of the key.
+    /// The encoded value is compared byte for byte with the stored one, the data type doesn't have
+    /// to implement [`PartialEq`]. Skipping the identical writes avoids dirtying pages for nothing.
+    /// Returns `true` if the value was written.
     ///
-    /// [`clear`]: crate::Database::clear
-    /// [`..`]: std::ops::RangeFull
+    /// This method can't be used with a database created with the [`DatabaseFlags::DUP_SORT`] flag.
     ///
     /// ```
     /// # use std::fs;
@@ -1723,54 +2218,73 @@ impl PolyDatabase {
     /// type BEI32 = I32<BigEndian>;
     ///
     /// let mut wtxn = env.write_txn()?;
-    /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("put-if-changed-i32"))?;
     ///
     /// # db.clear(&mut wtxn)?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &13, "i-am-thirteen")?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    /// let written = db.put_if_changed::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    /// assert!(written);
     ///
-    /// let range = 27..=42;
-    /// let ret = db.delete_range::<BEI32, _>(&mut wtxn, &range)?;
-    /// assert_eq!(ret, 2);
+    /// let written = db.put_if_changed::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    /// assert!(!written);
     ///
-    /// let mut iter = db.iter::<BEI32, Str>(&wtxn)?;
-    /// assert_eq!(iter.next().transpose()?, Some((13, "i-am-thirteen")));
-    /// assert_eq!(iter.next().transpose()?, Some((521, "i-am-five-hundred-and-twenty-one")));
-    /// assert_eq!(iter.next().transpose()?, None);
+    /// let written = db.put_if_changed::<BEI32, Str>(&mut wtxn, &42, "i-am-the-answer")?;
+    /// assert!(written);
+    ///
+    /// let ret = db.get::<BEI32, Str>(&wtxn, &42)?;
+    /// assert_eq!(ret, Some("i-am-the-answer"));
     ///
-    /// drop(iter);
     /// wtxn.commit()?;
     /// # Ok(()) }
     /// ```
-    pub fn delete_range<'a, 'txn, KC, R>(&self, txn: &'txn mut RwTxn, range: &'a R) -> Result<usize>
+    pub fn put_if_changed<'a, KC, DC>(
+        &self,
+        txn: &mut RwTxn,
+        key: &'a KC::EItem,
+        data: &'a DC::EItem,
+    ) -> Result<bool>
     where
-        KC: BytesEncode<'a> + BytesDecode<'txn>,
-        R: RangeBounds<KC::EItem>,
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
     {
         assert_eq_env_db_txn!(self, txn);
 
-        let mut count = 0;
-        let mut iter = self.range_mut::<KC, DecodeIgnore, _>(txn, range)?;
+        let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
+        let data_bytes: Cow<[u8]> = DC::bytes_encode(&data).map_err(Error::Encoding)?;
 
-        while iter.next().is_some() {
-            // safety: We do not keep any reference from the database while using `del_current`.
-            //         The user can't keep any reference inside of the database as we ask for a
-            //         mutable reference to the `txn`.
-            unsafe { iter.del_current()? };
-            count += 1;
+        let mut key_val = unsafe { crate::into_val(&key_bytes) };
+        let mut stored_val = mem::MaybeUninit::uninit();
+
+        let result = unsafe {
+            mdb_result(ffi::mdb_get(txn.txn.txn, self.dbi, &mut key_val, stored_val.as_mut_ptr()))
+        };
+
+        match result {
+            Ok(()) => {
+                let stored = unsafe { crate::from_val(stored_val.assume_init()) };
+                if stored == &data_bytes[..] {
+                    return Ok(false);
+                }
+            }
+            Err(e) if e.not_found() => (),
+            Err(e) => return Err(e.into()),
         }
 
-        Ok(count)
+        let mut data_val = unsafe { crate::into_val(&data_bytes) };
+        unsafe {
+            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut data_val, 0))
+                .map_err(|e| txn.write_error(e))?
+        }
+
+        Ok(true)
     }
 
-    /// Deletes all key/value pairs in this database.
+    /// Insert all the given key-value pairs in this database, reusing a single write cursor.
     ///
-    /// Perfer using this method instead of a call to [`delete_range`] with a full range ([`..`]).
+    /// This is cheaper than calling [`Self::put`] in a loop when loading many entries.
+    /// It returns the number of entries that were written.
     ///
-    /// [`delete_range`]: crate::Database::delete_range
-    /// [`..`]: std::ops::RangeFull
+    /// If the database was created with the [`DatabaseFlags::DUP_SORT`] flag, the values
+    /// are added to the sorted duplicate values of the keys instead of replacing them.
     ///
     /// ```
     /// # use std::fs;
@@ -1789,43 +2303,112 @@ impl PolyDatabase {
     /// type BEI32 = I32<BigEndian>;
     ///
     /// let mut wtxn = env.write_txn()?;
-    /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("put-multi-i32"))?;
     ///
     /// # db.clear(&mut wtxn)?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &13, "i-am-thirteen")?;
-    /// db.put::<BEI32, Str>(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    /// let items = [(&42, "i-am-forty-two"), (&27, "i-am-twenty-seven"), (&13, "i-am-thirteen")];
+    /// let count = db.put_multi::<BEI32, Str, _>(&mut wtxn, items)?;
+    /// assert_eq!(count, 3);
     ///
-    /// db.clear(&mut wtxn)?;
+    /// let ret = db.get::<BEI32, Str>(&wtxn, &27)?;
+    /// assert_eq!(ret, Some("i-am-twenty-seven"));
+    /// assert_eq!(db.len(&wtxn)?, 3);
     ///
-    /// let ret = db.is_empty(&wtxn)?;
-    /// assert!(ret);
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn put_multi<'a, KC, DC, I>(&self, txn: &mut RwTxn, items: I) -> Result<usize>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
+        I: IntoIterator<Item = (&'a KC::EItem, &'a DC::EItem)>,
+    {
+        self.put_multi_with_flags::<KC, DC, I>(txn, items, PutFlags::empty())
+    }
+
+    /// Insert all the given key-value pairs in this database with the given put flags,
+    /// reusing a single write cursor.
+    ///
+    /// When the entries are already sorted by key, [`PutFlags::APPEND`] makes the bulk
+    /// loading even faster, the first out of order key stops the insertion with a
+    /// [`MdbError::KeyExist`] error. It returns the number of entries that were written.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{Database, Error, MdbError, PutFlags};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("put-multi-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// let items = [(&13, "i-am-thirteen"), (&27, "i-am-twenty-seven"), (&42, "i-am-forty-two")];
+    /// let count = db.put_multi_with_flags::<BEI32, Str, _>(&mut wtxn, items, PutFlags::APPEND)?;
+    /// assert_eq!(count, 3);
+    ///
+    /// let items = [(&43, "i-am-forty-three"), (&21, "i-am-twenty-one")];
+    /// let ret = db.put_multi_with_flags::<BEI32, Str, _>(&mut wtxn, items, PutFlags::APPEND);
+    /// assert!(matches!(ret, Err(Error::Mdb(MdbError::KeyExist))));
+    ///
+    /// let ret = db.get::<BEI32, Str>(&wtxn, &43)?;
+    /// assert_eq!(ret, Some("i-am-forty-three"));
+    /// let ret = db.get::<BEI32, Str>(&wtxn, &21)?;
+    /// assert_eq!(ret, None);
     ///
     /// wtxn.commit()?;
     /// # Ok(()) }
     /// ```
-    pub fn clear(&self, txn: &mut RwTxn) -> Result<()> {
+    pub fn put_multi_with_flags<'a, KC, DC, I>(
+        &self,
+        txn: &mut RwTxn,
+        items: I,
+        flags: PutFlags,
+    ) -> Result<usize>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
+        I: IntoIterator<Item = (&'a KC::EItem, &'a DC::EItem)>,
+    {
         assert_eq_env_db_txn!(self, txn);
 
-        unsafe { mdb_result(ffi::mdb_drop(txn.txn.txn, self.dbi, 0)).map_err(Into::into) }
+        let mut cursor = RwCursor::new(txn, self.dbi)?;
+        let mut count = 0;
+
+        for (key, data) in items {
+            let key_bytes: Cow<[u8]> = KC::bytes_encode(key).map_err(Error::Encoding)?;
+            let data_bytes: Cow<[u8]> = DC::bytes_encode(data).map_err(Error::Encoding)?;
+
+            // safety: the encoded key and data are owned by us or by the caller,
+            //         they never come from this database.
+            unsafe { cursor.put_with_flags(&key_bytes, &data_bytes, flags)? }
+
+            count += 1;
+        }
+
+        Ok(count)
     }
 
-    /// Read this polymorphic database like a typed one, specifying the codecs.
-    ///
-    /// # Safety
-    ///
-    /// It is up to you to ensure that the data read and written using the polymorphic
-    /// handle correspond to the the typed, uniform one. If an invalid write is made,
-    /// it can corrupt the database from the eyes of heed.
+    /// Retrieves the value associated with a key or inserts the given default one if
+    /// the key doesn't exist yet, the key is only searched once.
     ///
-    /// # Example
+    /// This method can't be used with a database created with the [`DatabaseFlags::DUP_SORT`] flag.
     ///
     /// ```
     /// # use std::fs;
     /// # use std::path::Path;
     /// # use heed::EnvOpenOptions;
-    /// use heed::{Database, PolyDatabase};
+    /// use heed::Database;
     /// use heed::types::*;
     /// use heed::byteorder::BigEndian;
     ///
@@ -1841,18 +2424,700 @@ impl PolyDatabase {
     /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
     ///
     /// # db.clear(&mut wtxn)?;
-    /// // We remap the types for ease of use.
-    /// let db = db.as_uniform::<BEI32, Str>();
-    /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
-    /// db.put(&mut wtxn, &27, "i-am-twenty-seven")?;
-    /// db.put(&mut wtxn, &13, "i-am-thirteen")?;
-    /// db.put(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    ///
+    /// let ret = db.get_or_put::<BEI32, Str>(&mut wtxn, &42, "i-am-the-default")?;
+    /// assert_eq!(ret, "i-am-forty-two");
+    ///
+    /// let ret = db.get_or_put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// assert_eq!(ret, "i-am-twenty-seven");
+    ///
+    /// let ret = db.get::<BEI32, Str>(&wtxn, &27)?;
+    /// assert_eq!(ret, Some("i-am-twenty-seven"));
     ///
     /// wtxn.commit()?;
     /// # Ok(()) }
     /// ```
-    pub fn as_uniform<KC, DC>(&self) -> Database<KC, DC> {
-        Database::new(self.env_ident, self.dbi)
+    pub fn get_or_put<'a, 'txn, KC, DC>(
+        &self,
+        txn: &'txn mut RwTxn,
+        key: &'a KC::EItem,
+        data: &'a DC::EItem,
+    ) -> Result<DC::DItem>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a> + BytesDecode<'txn>,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
+        let data_bytes: Cow<[u8]> = DC::bytes_encode(&data).map_err(Error::Encoding)?;
+
+        let data = self.raw_get_or_put(txn, &key_bytes, &data_bytes)?;
+        DC::bytes_decode(data).map_err(Error::Decoding)
+    }
+
+    /// Retrieves the value associated with a key or inserts the one returned by the
+    /// given function if the key doesn't exist yet, the function is only called in this case.
+    ///
+    /// This method can't be used with a database created with the [`DatabaseFlags::DUP_SORT`] flag.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    ///
+    /// let ret = db.get_or_put_with::<BEI32, Str, _, _, _>(&mut wtxn, &42, || {
+    ///     String::from("i-am-the-default")
+    /// })?;
+    /// assert_eq!(ret, "i-am-forty-two");
+    ///
+    /// let ret = db.get_or_put_with::<BEI32, Str, _, _, _>(&mut wtxn, &27, || {
+    ///     format!("i-am-{}", 27)
+    /// })?;
+    /// assert_eq!(ret, "i-am-27");
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_or_put_with<'a, 'txn, KC, DC, E, T, F>(
+        &self,
+        txn: &'txn mut RwTxn,
+        key: &'a KC::EItem,
+        default: F,
+    ) -> Result<DC::DItem>
+    where
+        KC: BytesEncode<'a>,
+        DC: for<'e> BytesEncode<'e, EItem = E> + BytesDecode<'txn>,
+        E: ?Sized + 'static,
+        T: Borrow<E>,
+        F: FnOnce() -> T,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
+        let mut key_val = unsafe { crate::into_val(&key_bytes) };
+        let mut data_val = mem::MaybeUninit::uninit();
+
+        let result = unsafe {
+            mdb_result(ffi::mdb_get(txn.txn.txn, self.dbi, &mut key_val, data_val.as_mut_ptr()))
+        };
+
+        let data = match result {
+            Ok(()) => unsafe { crate::from_val(data_val.assume_init()) },
+            Err(e) if e.not_found() => {
+                let data = default();
+                let data_bytes = DC::bytes_encode(data.borrow()).map_err(Error::Encoding)?;
+                self.raw_get_or_put(txn, &key_bytes, &data_bytes)?
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        DC::bytes_decode(data).map_err(Error::Decoding)
+    }
+
+    /// Inserts the data if the key doesn't exist, returns the data stored in the database.
+    fn raw_get_or_put<'txn>(
+        &self,
+        txn: &'txn mut RwTxn,
+        key: &[u8],
+        data: &[u8],
+    ) -> Result<&'txn [u8]> {
+        let mut key_val = unsafe { crate::into_val(key) };
+        let mut data_val = ffi::reserve_size_val(data.len());
+        let flags = ffi::MDB_NOOVERWRITE | ffi::MDB_RESERVE;
+
+        // LMDB makes the data point to the existing value if the key already exists,
+        // otherwise to the reserved space that we must fill.
+        let result = unsafe {
+            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut key_val, &mut data_val, flags))
+        };
+
+        match result {
+            Ok(()) => unsafe {
+                let ptr = data_val.mv_data as *mut u8;
+                ptr.copy_from_nonoverlapping(data.as_ptr(), data.len());
+            },
+            Err(MdbError::KeyExist) => (),
+            Err(e) => return Err(txn.write_error(e)),
+        }
+
+        Ok(unsafe { crate::from_val(data_val) })
+    }
+
+    /// Deletes a key-value pairs in this database.
+    ///
+    /// If the key does not exist, then `false` is returned.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &13, "i-am-thirteen")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    ///
+    /// let ret = db.delete::<BEI32>(&mut wtxn, &27)?;
+    /// assert_eq!(ret, true);
+    ///
+    /// let ret = db.get::<BEI32, Str>(&mut wtxn, &27)?;
+    /// assert_eq!(ret, None);
+    ///
+    /// let ret = db.delete::<BEI32>(&mut wtxn, &467)?;
+    /// assert_eq!(ret, false);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn delete<'a, KC>(&self, txn: &mut RwTxn, key: &'a KC::EItem) -> Result<bool>
+    where
+        KC: BytesEncode<'a>,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
+        let mut key_val = unsafe { crate::into_val(&key_bytes) };
+
+        let result = unsafe {
+            mdb_result(ffi::mdb_del(txn.txn.txn, self.dbi, &mut key_val, ptr::null_mut()))
+        };
+
+        match result {
+            Ok(()) => Ok(true),
+            Err(e) if e.not_found() => Ok(false),
+            Err(e) => Err(txn.write_error(e)),
+        }
+    }
+
+    /// Moves the value of a key to another key of this database.
+    ///
+    /// The value is read, written under the new key and the old key is deleted, all of this
+    /// in the given transaction. If the new key already exists its value is overwritten.
+    /// Returns `false`, without writing anything, if the old key does not exist.
+    ///
+    /// This method can't be used with a database created with the [`DatabaseFlags::DUP_SORT`] flag,
+    /// an [`MdbError::Incompatible`] error is returned otherwise.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("move-key-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    ///
+    /// let moved = db.move_key::<BEI32>(&mut wtxn, &27, &13)?;
+    /// assert!(moved);
+    /// assert_eq!(db.get::<BEI32, Str>(&wtxn, &27)?, None);
+    /// assert_eq!(db.get::<BEI32, Str>(&wtxn, &13)?, Some("i-am-twenty-seven"));
+    ///
+    /// // The value of an existing key is overwritten.
+    /// let moved = db.move_key::<BEI32>(&mut wtxn, &13, &42)?;
+    /// assert!(moved);
+    /// assert_eq!(db.get::<BEI32, Str>(&wtxn, &42)?, Some("i-am-twenty-seven"));
+    ///
+    /// let moved = db.move_key::<BEI32>(&mut wtxn, &13, &42)?;
+    /// assert!(!moved);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn move_key<'a, KC>(
+        &self,
+        txn: &mut RwTxn,
+        from: &'a KC::EItem,
+        to: &'a KC::EItem,
+    ) -> Result<bool>
+    where
+        KC: BytesEncode<'a>,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        // Only the first duplicate would be moved, the other ones would be deleted.
+        let mut flags = 0;
+        unsafe { mdb_result(ffi::mdb_dbi_flags(txn.txn.txn, self.dbi, &mut flags))? };
+        if DatabaseFlags::from_bits_truncate(flags).contains(DatabaseFlags::DUP_SORT) {
+            return Err(MdbError::Incompatible.into());
+        }
+
+        let from_bytes: Cow<[u8]> = KC::bytes_encode(from).map_err(Error::Encoding)?;
+        let to_bytes: Cow<[u8]> = KC::bytes_encode(to).map_err(Error::Encoding)?;
+
+        let mut from_val = unsafe { crate::into_val(&from_bytes) };
+        let mut to_val = unsafe { crate::into_val(&to_bytes) };
+        let mut data_val = mem::MaybeUninit::uninit();
+
+        let result = unsafe {
+            mdb_result(ffi::mdb_get(txn.txn.txn, self.dbi, &mut from_val, data_val.as_mut_ptr()))
+        };
+
+        // The value is copied as writing in the database invalidates it.
+        let data = match result {
+            Ok(()) => unsafe { crate::from_val(data_val.assume_init()) }.to_vec(),
+            Err(e) if e.not_found() => return Ok(false),
+            Err(e) => return Err(e.into()),
+        };
+
+        // The keys are compared with the comparator of the database, which can consider
+        // keys with different bytes equal, writing the new key would overwrite the old one.
+        if unsafe { ffi::mdb_cmp(txn.txn.txn, self.dbi, &from_val, &to_val) } == 0 {
+            return Ok(true);
+        }
+
+        let mut data_val = unsafe { crate::into_val(&data) };
+
+        unsafe {
+            mdb_result(ffi::mdb_put(txn.txn.txn, self.dbi, &mut to_val, &mut data_val, 0))
+                .map_err(|e| txn.write_error(e))?;
+            mdb_result(ffi::mdb_del(txn.txn.txn, self.dbi, &mut from_val, ptr::null_mut()))
+                .map_err(|e| txn.write_error(e))?;
+        }
+
+        Ok(true)
+    }
+
+    /// Deletes a single duplicate value of a key in a database created with the
+    /// [`DatabaseFlags::DUP_SORT`] flag.
+    ///
+    /// If the key/value pair does not exist, then `false` is returned.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{DatabaseFlags, PolyDatabase};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let flags = DatabaseFlags::DUP_SORT;
+    /// let db = env.create_poly_database_with_flags(&mut wtxn, Some("dup-i32"), flags)?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &35, "i-am-thirty-five")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &35, "i-am-also-thirty-five")?;
+    ///
+    /// let ret = db.delete_one::<BEI32, Str>(&mut wtxn, &35, "i-am-thirty-five")?;
+    /// assert_eq!(ret, true);
+    ///
+    /// let ret = db.delete_one::<BEI32, Str>(&mut wtxn, &35, "i-am-thirty-five")?;
+    /// assert_eq!(ret, false);
+    ///
+    /// let ret = db.get::<BEI32, Str>(&wtxn, &35)?;
+    /// assert_eq!(ret, Some("i-am-also-thirty-five"));
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn delete_one<'a, KC, DC>(
+        &self,
+        txn: &mut RwTxn,
+        key: &'a KC::EItem,
+        data: &'a DC::EItem,
+    ) -> Result<bool>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        let key_bytes: Cow<[u8]> = KC::bytes_encode(&key).map_err(Error::Encoding)?;
+        let data_bytes: Cow<[u8]> = DC::bytes_encode(&data).map_err(Error::Encoding)?;
+        let mut key_val = unsafe { crate::into_val(&key_bytes) };
+        let mut data_val = unsafe { crate::into_val(&data_bytes) };
+
+        let result =
+            unsafe { mdb_result(ffi::mdb_del(txn.txn.txn, self.dbi, &mut key_val, &mut data_val)) };
+
+        match result {
+            Ok(()) => Ok(true),
+            Err(e) if e.not_found() => Ok(false),
+            Err(e) => Err(txn.write_error(e)),
+        }
+    }
+
+    /// Deletes a range of key-value pairs in this database.
+    ///
+    /// Perfer using [`clear`] instead of a call to this method with a full range ([`..`]).
+    ///
+    /// Comparisons are made by using the bytes representation of the key.
+    ///
+    /// [`clear`]: crate::Database::clear
+    /// [`..`]: std::ops::RangeFull
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &13, "i-am-thirteen")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    ///
+    /// let range = 27..=42;
+    /// let ret = db.delete_range::<BEI32, _>(&mut wtxn, &range)?;
+    /// assert_eq!(ret, 2);
+    ///
+    /// let mut iter = db.iter::<BEI32, Str>(&wtxn)?;
+    /// assert_eq!(iter.next().transpose()?, Some((13, "i-am-thirteen")));
+    /// assert_eq!(iter.next().transpose()?, Some((521, "i-am-five-hundred-and-twenty-one")));
+    /// assert_eq!(iter.next().transpose()?, None);
+    ///
+    /// drop(iter);
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn delete_range<'a, 'txn, KC, R>(&self, txn: &'txn mut RwTxn, range: &'a R) -> Result<usize>
+    where
+        KC: BytesEncode<'a>,
+        R: RangeBounds<KC::EItem>,
+    {
+        assert_eq_env_db_txn!(self, txn);
+
+        let mut count = 0;
+        let mut iter =
+            self.range_mut::<KC, DecodeIgnore, _>(txn, range)?.remap_key_type::<DecodeIgnore>();
+
+        // The keys are never decoded, only LMDB errors are reported.
+        while iter.next().transpose()?.is_some() {
+            // safety: We do not keep any reference from the database while using `del_current`.
+            //         The user can't keep any reference inside of the database as we ask for a
+            //         mutable reference to the `txn`.
+            unsafe { iter.del_current()? };
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// Deletes all key/value pairs in this database.
+    ///
+    /// The database is only emptied, not destroyed: it stays registered in the environment,
+    /// its flags are kept and this handle remains valid for subsequent writes, in this
+    /// transaction as in the next ones. The deletion is part of the transaction and is
+    /// discarded if the transaction is aborted.
+    /// Use [`delete_database`] to remove the database itself from the environment.
+    ///
+    /// Prefer using this method instead of a call to [`delete_range`] with a full range ([`..`]).
+    ///
+    /// [`delete_range`]: crate::Database::delete_range
+    /// [`..`]: std::ops::RangeFull
+    /// [`delete_database`]: crate::PolyDatabase::delete_database
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &13, "i-am-thirteen")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    ///
+    /// db.clear(&mut wtxn)?;
+    ///
+    /// let ret = db.is_empty(&wtxn)?;
+    /// assert!(ret);
+    ///
+    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    /// let ret = db.len(&wtxn)?;
+    /// assert_eq!(ret, 1);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn clear(&self, txn: &mut RwTxn) -> Result<()> {
+        assert_eq_env_db_txn!(self, txn);
+
+        unsafe {
+            mdb_result(ffi::mdb_drop(txn.txn.txn, self.dbi, 0)).map_err(|e| txn.write_error(e))
+        }
+    }
+
+    /// Deletes this database from the environment, its entries are removed and its pages
+    /// are reclaimed, the name is then free to be used for a new database.
+    ///
+    /// This handle is consumed as it becomes invalid. The deletion is part of the transaction,
+    /// yet the handles are closed even if the transaction is aborted, the database can be
+    /// reopened then.
+    ///
+    /// The unnamed database can't be deleted, it is only emptied like with [`Self::clear`],
+    /// its handles stay valid and it can't be reopened with other types.
+    ///
+    /// # Safety
+    ///
+    /// The handles are [`Copy`] and LMDB gives the dbi of a deleted database to the next one
+    /// opened in this environment. The caller must ensure that no other handle to this named
+    /// database, i.e. a copy of this one, is used after this call, it would silently read and
+    /// write another database with the wrong types. No other thread must use this database
+    /// while it is deleted either, LMDB closes its handle.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("tenant-42"))?;
+    ///
+    /// db.put::<BEI32, Str>(&mut wtxn, &42, "i-am-forty-two")?;
+    /// db.put::<BEI32, Str>(&mut wtxn, &27, "i-am-twenty-seven")?;
+    ///
+    /// unsafe { db.delete_database(&mut wtxn)? };
+    /// wtxn.commit()?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// assert!(env.open_poly_database(&rtxn, Some("tenant-42"))?.is_none());
+    /// # Ok(()) }
+    /// ```
+    pub unsafe fn delete_database(self, txn: &mut RwTxn) -> Result<()> {
+        assert_eq_env_db_txn!(self, txn);
+
+        unsafe {
+            mdb_result(ffi::mdb_drop(txn.txn.txn, self.dbi, 1)).map_err(|e| txn.write_error(e))?
+        }
+
+        // LMDB closed the handle of a named database, the dbi can be given to another database,
+        // the unnamed one is only emptied and keeps its types and comparator.
+        if self.dbi != MAIN_DBI {
+            txn.env().forget_database(self.dbi);
+        }
+        Ok(())
+    }
+
+    /// Sets a custom comparator to order the keys of this database.
+    ///
+    /// LMDB doesn't store the comparator, it must be set before any data is read or written
+    /// in the database, and again each time the environment is opened, before any other use
+    /// of the database. Using a database with another ordering than the one that was used
+    /// to write it will make LMDB misplace and miss the keys.
+    ///
+    /// Trying to set a different comparator on a database that already contains entries
+    /// returns an [`Error::ComparatorChanged`] if another one was set in this environment,
+    /// setting the same comparator again is a no-op.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use std::cmp::Ordering;
+    /// use heed::{Comparator, Database};
+    /// use heed::types::*;
+    ///
+    /// /// Orders version strings like `1.10.0` after `1.9.3`.
+    /// enum Version {}
+    ///
+    /// impl Comparator for Version {
+    ///     fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    ///         let parse = |bytes| {
+    ///             let s = std::str::from_utf8(bytes).unwrap_or_default();
+    ///             s.split('.').map(|n| n.parse().unwrap_or(0)).collect::<Vec<u64>>()
+    ///         };
+    ///         parse(a).cmp(&parse(b)).then_with(|| a.cmp(b))
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("versions"))?;
+    /// db.set_compare::<Version>(&mut wtxn)?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put::<Str, Unit>(&mut wtxn, "1.10.0", &())?;
+    /// db.put::<Str, Unit>(&mut wtxn, "1.2.0", &())?;
+    /// db.put::<Str, Unit>(&mut wtxn, "1.9.3", &())?;
+    ///
+    /// let mut iter = db.iter::<Str, Unit>(&wtxn)?;
+    /// assert_eq!(iter.next().transpose()?, Some(("1.2.0", ())));
+    /// assert_eq!(iter.next().transpose()?, Some(("1.9.3", ())));
+    /// assert_eq!(iter.next().transpose()?, Some(("1.10.0", ())));
+    /// assert_eq!(iter.next().transpose()?, None);
+    ///
+    /// drop(iter);
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_compare<C: Comparator + 'static>(&self, txn: &mut RwTxn) -> Result<()> {
+        assert_eq_env_db_txn!(self, txn);
+
+        let mut comparators = txn.env().comparators();
+        match comparators.get(&self.dbi) {
+            Some(type_id) if *type_id == TypeId::of::<C>() => return Ok(()),
+            Some(_) if !self.is_empty(txn)? => return Err(Error::ComparatorChanged),
+            _ => (),
+        }
+
+        unsafe {
+            mdb_result(ffi::mdb_set_compare(
+                txn.txn.txn,
+                self.dbi,
+                Some(custom_key_cmp_wrapper::<C>),
+            ))?
+        };
+
+        comparators.insert(self.dbi, TypeId::of::<C>());
+        Ok(())
+    }
+
+    /// Read this polymorphic database like a typed one, specifying the codecs.
+    ///
+    /// # Safety
+    ///
+    /// It is up to you to ensure that the data read and written using the polymorphic
+    /// handle correspond to the the typed, uniform one. If an invalid write is made,
+    /// it can corrupt the database from the eyes of heed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{Database, PolyDatabase};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_poly_database(&mut wtxn, Some("iter-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// // We remap the types for ease of use.
+    /// let db = db.as_uniform::<BEI32, Str>();
+    /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
+    /// db.put(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put(&mut wtxn, &13, "i-am-thirteen")?;
+    /// db.put(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn as_uniform<KC, DC>(&self) -> Database<KC, DC> {
+        Database::new(self.env_ident, self.dbi)
+    }
+}
+
+/// The function that LMDB calls to compare two keys with a [`Comparator`].
+extern "C" fn custom_key_cmp_wrapper<C: Comparator>(
+    a: *const ffi::MDB_val,
+    b: *const ffi::MDB_val,
+) -> std::os::raw::c_int {
+    let (a, b) = unsafe { (crate::from_val(*a), crate::from_val(*b)) };
+    match C::compare(a, b) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
     }
 }
 
@@ -1,5 +1,6 @@
+use std::borrow::Borrow;
 use std::ops::RangeBounds;
-use std::{any, fmt, marker};
+use std::{any, fmt, marker, mem};
 
 use crate::mdb::ffi;
 use crate::*;
@@ -168,6 +169,202 @@ impl<KC, DC> Database<KC, DC> {
         self.dyndb.get::<KC, DC>(txn, key)
     }
 
+    /// Retrieves the value associated with a key or the [`Default`] value of the data type
+    /// if the key does not exist.
+    ///
+    /// Prefer [`Self::get`] when the absence of the key must be distinguished from a default value.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<Str, BEI32> = env.create_database(&mut wtxn, Some("get-or-default-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, "visits", &42)?;
+    ///
+    /// let ret = db.get_or_default(&wtxn, "visits")?;
+    /// assert_eq!(ret, 42);
+    ///
+    /// let ret = db.get_or_default(&wtxn, "downloads")?;
+    /// assert_eq!(ret, 0);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_or_default<'a, 'txn>(
+        &self,
+        txn: &'txn RoTxn,
+        key: &'a KC::EItem,
+    ) -> Result<DC::DItem>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesDecode<'txn>,
+        DC::DItem: Default,
+    {
+        self.dyndb.get_or_default::<KC, DC>(txn, key)
+    }
+
+    /// Returns `true` if the key exists in this database, the value is never decoded.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<Str, BEI32> = env.create_database(&mut wtxn, Some("exists-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, "i-am-forty-two", &42)?;
+    /// db.put(&mut wtxn, "i-am-twenty-seven", &27)?;
+    ///
+    /// assert!(db.exists(&wtxn, "i-am-forty-two")?);
+    /// assert!(!db.exists(&wtxn, "i-am-twenty-one")?);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn exists<'a>(&self, txn: &RoTxn, key: &'a KC::EItem) -> Result<bool>
+    where
+        KC: BytesEncode<'a>,
+    {
+        self.dyndb.exists::<KC>(txn, key)
+    }
+
+    /// Retrieves all the duplicate values of a key in a database created with the
+    /// [`DatabaseFlags::DUP_SORT`] flag, returns `None` if the key doesn't exist.
+    ///
+    /// The values are returned in the sorted order they are stored in.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{Database, DatabaseFlags};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let flags = DatabaseFlags::DUP_SORT;
+    /// let db: Database<BEI32, Str> =
+    ///     env.create_database_with_flags(&mut wtxn, Some("dup-i32"), flags)?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, &68, "i-am-sixty-eight")?;
+    /// db.put(&mut wtxn, &35, "i-am-thirty-five")?;
+    /// db.put(&mut wtxn, &35, "i-am-also-thirty-five")?;
+    /// db.put(&mut wtxn, &0, "i-am-zero")?;
+    ///
+    /// let mut iter = db.get_duplicates(&wtxn, &35)?.unwrap();
+    /// assert_eq!(iter.next().transpose()?, Some((35, "i-am-also-thirty-five")));
+    /// assert_eq!(iter.next().transpose()?, Some((35, "i-am-thirty-five")));
+    /// assert_eq!(iter.next().transpose()?, None);
+    /// drop(iter);
+    ///
+    /// assert!(db.get_duplicates(&wtxn, &42)?.is_none());
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_duplicates<'a, 'txn>(
+        &self,
+        txn: &'txn RoTxn,
+        key: &'a KC::EItem,
+    ) -> Result<Option<RoIter<'txn, KC, DC>>>
+    where
+        KC: BytesEncode<'a>,
+    {
+        self.dyndb.get_duplicates::<KC, DC>(txn, key)
+    }
+
+    /// Retrieves all the duplicate values of a key in a database created with both the
+    /// [`DatabaseFlags::DUP_SORT`] and [`DatabaseFlags::DUP_FIXED`] flags,
+    /// returns `None` if the key doesn't exist.
+    ///
+    /// Unlike [`Database::get_duplicates`], the values are read from LMDB a whole page
+    /// at a time and split according to the [`FixedSize`](crate::types::FixedSize) of the
+    /// data codec, which is much faster when a key holds a lot of small values.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{Database, DatabaseFlags};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    /// type BEU64 = U64<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let flags = DatabaseFlags::DUP_SORT | DatabaseFlags::DUP_FIXED;
+    /// let db: Database<BEI32, BEU64> =
+    ///     env.create_database_with_flags(&mut wtxn, Some("dup-fixed-u64"), flags)?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, &68, &6800)?;
+    /// db.put(&mut wtxn, &35, &3501)?;
+    /// db.put(&mut wtxn, &35, &3500)?;
+    /// db.put(&mut wtxn, &0, &0)?;
+    ///
+    /// let iter = db.get_duplicates_bulk(&wtxn, &35)?.unwrap();
+    /// assert_eq!(iter.collect::<heed::Result<Vec<_>>>()?, vec![3500, 3501]);
+    ///
+    /// assert!(db.get_duplicates_bulk(&wtxn, &42)?.is_none());
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_duplicates_bulk<'a, 'txn>(
+        &self,
+        txn: &'txn RoTxn,
+        key: &'a KC::EItem,
+    ) -> Result<Option<RoBulkDuplicates<'txn, DC>>>
+    where
+        KC: BytesEncode<'a>,
+    {
+        self.dyndb.get_duplicates_bulk::<KC, DC>(txn, key)
+    }
+
     /// Retrieves the key/value pair lower than the given one in this database.
     ///
     /// If the database if empty or there is no key lower than the given one,
@@ -390,7 +587,7 @@ impl<KC, DC> Database<KC, DC> {
 
     /// Retrieves the first key/value pair of this database.
     ///
-    /// If the database if empty, then `None` is returned.
+    /// If the database is empty, then `None` is returned.
     ///
     /// Comparisons are made by using the bytes representation of the key.
     ///
@@ -414,6 +611,9 @@ impl<KC, DC> Database<KC, DC> {
     /// let db: Database<BEI32, Str> = env.create_database(&mut wtxn, Some("first-i32"))?;
     ///
     /// # db.clear(&mut wtxn)?;
+    /// let ret = db.first(&wtxn)?;
+    /// assert_eq!(ret, None);
+    ///
     /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
     /// db.put(&mut wtxn, &27, "i-am-twenty-seven")?;
     ///
@@ -433,7 +633,7 @@ impl<KC, DC> Database<KC, DC> {
 
     /// Retrieves the last key/value pair of this database.
     ///
-    /// If the database if empty, then `None` is returned.
+    /// If the database is empty, then `None` is returned.
     ///
     /// Comparisons are made by using the bytes representation of the key.
     ///
@@ -457,6 +657,9 @@ impl<KC, DC> Database<KC, DC> {
     /// let db: Database<BEI32, Str> = env.create_database(&mut wtxn, Some("last-i32"))?;
     ///
     /// # db.clear(&mut wtxn)?;
+    /// let ret = db.last(&wtxn)?;
+    /// assert_eq!(ret, None);
+    ///
     /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
     /// db.put(&mut wtxn, &27, "i-am-twenty-seven")?;
     ///
@@ -476,6 +679,11 @@ impl<KC, DC> Database<KC, DC> {
 
     /// Returns the number of elements in this database.
     ///
+    /// The count is read from the database statistics of the transaction snapshot,
+    /// it doesn't iterate over the entries. For a database opened with the
+    /// [`DatabaseFlags::DUP_SORT`] flag every duplicate value is counted, not only
+    /// the distinct keys.
+    ///
     /// ```
     /// # use std::fs;
     /// # use std::path::Path;
@@ -518,6 +726,8 @@ impl<KC, DC> Database<KC, DC> {
 
     /// Returns `true` if and only if this database is empty.
     ///
+    /// This is equivalent to checking that [`Self::len`] returns zero.
+    ///
     /// ```
     /// # use std::fs;
     /// # use std::path::Path;
@@ -598,6 +808,110 @@ impl<KC, DC> Database<KC, DC> {
         self.dyndb.iter::<KC, DC>(txn)
     }
 
+    /// Return a lexicographically ordered iterator of the key-value pairs in this database
+    /// whose keys are strictly greater than the given one.
+    ///
+    /// This is the building block of keyset pagination: the last key of a page is enough
+    /// to resume the iteration in a fresh transaction. The given key doesn't need to exist,
+    /// if it was deleted between two pages the iteration resumes at the next existing key.
+    ///
+    /// If the database was created with the [`DatabaseFlags::DUP_SORT`] flag, all the
+    /// duplicate data of the given key are skipped.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<BEI32, Str> = env.create_database(&mut wtxn, Some("iter-from-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, &13, "i-am-thirteen")?;
+    /// db.put(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
+    /// db.put(&mut wtxn, &521, "i-am-five-hundred-and-twenty-one")?;
+    /// wtxn.commit()?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// let page: Vec<_> = db.iter_from(&rtxn, &13)?.take(2).collect::<Result<_, _>>()?;
+    /// assert_eq!(page, [(27, "i-am-twenty-seven"), (42, "i-am-forty-two")]);
+    /// let (last_seen, _) = page[1];
+    /// drop(rtxn);
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// db.delete(&mut wtxn, &last_seen)?;
+    /// wtxn.commit()?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// let mut iter = db.iter_from(&rtxn, &last_seen)?;
+    /// assert_eq!(iter.next().transpose()?, Some((521, "i-am-five-hundred-and-twenty-one")));
+    /// assert_eq!(iter.next().transpose()?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn iter_from<'a, 'txn>(
+        &self,
+        txn: &'txn RoTxn,
+        after: &'a KC::EItem,
+    ) -> Result<RoIter<'txn, KC, DC>>
+    where
+        KC: BytesEncode<'a>,
+    {
+        self.dyndb.iter_from::<KC, DC>(txn, after)
+    }
+
+    /// Return a lexicographically ordered iterator of all the raw key-value pairs in this database.
+    ///
+    /// The keys and data are yielded as the bytes stored in LMDB, no codec is involved.
+    /// This is useful to dump, copy or checksum a database without paying for the decoding.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<BEI32, Str> = env.create_database(&mut wtxn, Some("iter-raw-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put(&mut wtxn, &13, "i-am-thirteen")?;
+    ///
+    /// let mut iter = db.iter_raw(&wtxn)?;
+    /// assert_eq!(iter.next().transpose()?, Some((&[0, 0, 0, 13][..], &b"i-am-thirteen"[..])));
+    /// assert_eq!(iter.next().transpose()?, Some((&[0, 0, 0, 27][..], &b"i-am-twenty-seven"[..])));
+    /// assert_eq!(iter.next().transpose()?, None);
+    ///
+    /// drop(iter);
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn iter_raw<'txn>(&self, txn: &'txn RoTxn) -> Result<RoIterRaw<'txn>> {
+        self.dyndb.iter_raw(txn)
+    }
+
     /// Return a mutable lexicographically ordered iterator of all key-value pairs in this database.
     ///
     /// ```
@@ -747,7 +1061,8 @@ impl<KC, DC> Database<KC, DC> {
 
     /// Return a lexicographically ordered iterator of a range of key-value pairs in this database.
     ///
-    /// Comparisons are made by using the bytes representation of the key.
+    /// Comparisons are made by using the bytes representation of the key with the comparator
+    /// of the database, see [`Database::set_compare`], the bounds must follow its order.
     ///
     /// ```
     /// # use std::fs;
@@ -799,7 +1114,8 @@ impl<KC, DC> Database<KC, DC> {
     /// Return a mutable lexicographically ordered iterator of a range of
     /// key-value pairs in this database.
     ///
-    /// Comparisons are made by using the bytes representation of the key.
+    /// Comparisons are made by using the bytes representation of the key with the comparator
+    /// of the database, see [`Database::set_compare`], the bounds must follow its order.
     ///
     /// ```
     /// # use std::fs;
@@ -864,7 +1180,8 @@ impl<KC, DC> Database<KC, DC> {
     /// Return a reversed lexicographically ordered iterator of a range of key-value
     /// pairs in this database.
     ///
-    /// Comparisons are made by using the bytes representation of the key.
+    /// Comparisons are made by using the bytes representation of the key with the comparator
+    /// of the database, see [`Database::set_compare`], the bounds must follow its order.
     ///
     /// ```
     /// # use std::fs;
@@ -916,7 +1233,8 @@ impl<KC, DC> Database<KC, DC> {
     /// Return a mutable reversed lexicographically ordered iterator of a range of
     /// key-value pairs in this database.
     ///
-    /// Comparisons are made by using the bytes representation of the key.
+    /// Comparisons are made by using the bytes representation of the key with the comparator
+    /// of the database, see [`Database::set_compare`], the bounds must follow its order.
     ///
     /// ```
     /// # use std::fs;
@@ -1030,8 +1348,13 @@ impl<KC, DC> Database<KC, DC> {
         self.dyndb.prefix_iter::<KC, DC>(txn, prefix)
     }
 
-    /// Return a mutable lexicographically ordered iterator of all key-value pairs
-    /// in this database that starts with the given prefix.
+    /// Return a lexicographically ordered iterator of all key-value pairs
+    /// in this database whose encoded key starts with the given bytes.
+    ///
+    /// Unlike [`Self::prefix_iter`], the prefix isn't encoded with the key codec. It is useful
+    /// with composite keys, to iterate over the entries sharing the same leading fields
+    /// without knowing the following ones. The prefix must be a valid leading portion of
+    /// the bytes produced by the key codec, the keys are still decoded with it.
     ///
     /// Comparisons are made by using the bytes representation of the key.
     ///
@@ -1041,7 +1364,6 @@ impl<KC, DC> Database<KC, DC> {
     /// # use heed::EnvOpenOptions;
     /// use heed::Database;
     /// use heed::types::*;
-    /// use heed::byteorder::BigEndian;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let dir = tempfile::tempdir()?;
@@ -1049,44 +1371,98 @@ impl<KC, DC> Database<KC, DC> {
     /// #     .map_size(10 * 1024 * 1024) // 10MB
     /// #     .max_dbs(3000)
     /// #     .open(dir.path())?;
-    /// type BEI32 = I32<BigEndian>;
+    /// // The keys are a big endian user id followed by a big endian timestamp.
+    /// fn key(user_id: u32, timestamp: u32) -> Vec<u8> {
+    ///     [user_id.to_be_bytes(), timestamp.to_be_bytes()].concat()
+    /// }
     ///
     /// let mut wtxn = env.write_txn()?;
-    /// let db: Database<Str, BEI32> = env.create_database(&mut wtxn, Some("iter-i32"))?;
+    /// let db: Database<Bytes, Str> = env.create_database(&mut wtxn, Some("events"))?;
     ///
     /// # db.clear(&mut wtxn)?;
-    /// db.put(&mut wtxn, "i-am-twenty-eight", &28)?;
-    /// db.put(&mut wtxn, "i-am-twenty-seven", &27)?;
-    /// db.put(&mut wtxn, "i-am-twenty-nine",  &29)?;
-    /// db.put(&mut wtxn, "i-am-forty-one",    &41)?;
-    /// db.put(&mut wtxn, "i-am-forty-two",    &42)?;
-    ///
-    /// let mut iter = db.prefix_iter_mut(&mut wtxn, "i-am-twenty")?;
-    /// assert_eq!(iter.next().transpose()?, Some(("i-am-twenty-eight", 28)));
-    /// let ret = unsafe { iter.del_current()? };
-    /// assert!(ret);
-    ///
-    /// assert_eq!(iter.next().transpose()?, Some(("i-am-twenty-nine", 29)));
-    /// assert_eq!(iter.next().transpose()?, Some(("i-am-twenty-seven", 27)));
-    /// let ret = unsafe { iter.put_current("i-am-twenty-seven", &27000)? };
-    /// assert!(ret);
-    ///
+    /// db.put(&mut wtxn, &key(27, 1200), "logged-in")?;
+    /// db.put(&mut wtxn, &key(42, 1500), "logged-out")?;
+    /// db.put(&mut wtxn, &key(42, 1000), "logged-in")?;
+    /// db.put(&mut wtxn, &key(43, 900), "logged-in")?;
+    ///
+    /// let mut iter = db.prefix_iter_bytes(&wtxn, &42u32.to_be_bytes())?;
+    /// assert_eq!(iter.next().transpose()?, Some((&key(42, 1000)[..], "logged-in")));
+    /// assert_eq!(iter.next().transpose()?, Some((&key(42, 1500)[..], "logged-out")));
     /// assert_eq!(iter.next().transpose()?, None);
     ///
     /// drop(iter);
-    ///
-    /// let ret = db.get(&wtxn, "i-am-twenty-eight")?;
-    /// assert_eq!(ret, None);
-    ///
-    /// let ret = db.get(&wtxn, "i-am-twenty-seven")?;
-    /// assert_eq!(ret, Some(27000));
-    ///
     /// wtxn.commit()?;
     /// # Ok(()) }
     /// ```
-    pub fn prefix_iter_mut<'a, 'txn>(
+    pub fn prefix_iter_bytes<'txn>(
         &self,
-        txn: &'txn mut RwTxn,
+        txn: &'txn RoTxn,
+        prefix: &[u8],
+    ) -> Result<RoPrefix<'txn, KC, DC>> {
+        self.dyndb.prefix_iter_bytes::<KC, DC>(txn, prefix)
+    }
+
+    /// Return a mutable lexicographically ordered iterator of all key-value pairs
+    /// in this database that starts with the given prefix.
+    ///
+    /// The write transaction stays exclusively borrowed by the iterator until it is dropped,
+    /// the entries can be updated or deleted in the same cursor pass with
+    /// [`RwPrefix::put_current`] and [`RwPrefix::del_current`].
+    ///
+    /// Comparisons are made by using the bytes representation of the key.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<Str, BEI32> = env.create_database(&mut wtxn, Some("iter-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, "i-am-twenty-eight", &28)?;
+    /// db.put(&mut wtxn, "i-am-twenty-seven", &27)?;
+    /// db.put(&mut wtxn, "i-am-twenty-nine",  &29)?;
+    /// db.put(&mut wtxn, "i-am-forty-one",    &41)?;
+    /// db.put(&mut wtxn, "i-am-forty-two",    &42)?;
+    ///
+    /// let mut iter = db.prefix_iter_mut(&mut wtxn, "i-am-twenty")?;
+    /// assert_eq!(iter.next().transpose()?, Some(("i-am-twenty-eight", 28)));
+    /// let ret = unsafe { iter.del_current()? };
+    /// assert!(ret);
+    ///
+    /// assert_eq!(iter.next().transpose()?, Some(("i-am-twenty-nine", 29)));
+    /// assert_eq!(iter.next().transpose()?, Some(("i-am-twenty-seven", 27)));
+    /// let ret = unsafe { iter.put_current("i-am-twenty-seven", &27000)? };
+    /// assert!(ret);
+    ///
+    /// assert_eq!(iter.next().transpose()?, None);
+    ///
+    /// drop(iter);
+    ///
+    /// let ret = db.get(&wtxn, "i-am-twenty-eight")?;
+    /// assert_eq!(ret, None);
+    ///
+    /// let ret = db.get(&wtxn, "i-am-twenty-seven")?;
+    /// assert_eq!(ret, Some(27000));
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn prefix_iter_mut<'a, 'txn>(
+        &self,
+        txn: &'txn mut RwTxn,
         prefix: &'a KC::EItem,
     ) -> Result<RwPrefix<'txn, KC, DC>>
     where
@@ -1214,6 +1590,9 @@ impl<KC, DC> Database<KC, DC> {
 
     /// Insert a key-value pairs in this database.
     ///
+    /// If the database was created with the [`DatabaseFlags::DUP_SORT`] flag, the value
+    /// is added to the sorted duplicate values of the key instead of replacing them.
+    ///
     /// ```
     /// # use std::fs;
     /// # use std::path::Path;
@@ -1301,6 +1680,56 @@ impl<KC, DC> Database<KC, DC> {
         self.dyndb.put_reserved::<KC, F>(txn, key, data_size, write_func)
     }
 
+    /// Reserve space for a value of the given size and return it to be directly written.
+    ///
+    /// The returned slice points into the memory map and is only valid until the next
+    /// write operation on the transaction. Its bytes are uninitialized, every one of them
+    /// must be written before the value is read or the transaction committed.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_database::<BEI32, Str>(&mut wtxn, Some("number-string"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// let value = "I am a long long long value";
+    /// let reserved = db.put_reserve(&mut wtxn, &42, value.len())?;
+    /// for (byte, value_byte) in reserved.iter_mut().zip(value.as_bytes()) {
+    ///     byte.write(*value_byte);
+    /// }
+    ///
+    /// let ret = db.get(&mut wtxn, &42)?;
+    /// assert_eq!(ret, Some(value));
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn put_reserve<'a, 'txn>(
+        &self,
+        txn: &'txn mut RwTxn,
+        key: &'a KC::EItem,
+        data_size: usize,
+    ) -> Result<&'txn mut [mem::MaybeUninit<u8>]>
+    where
+        KC: BytesEncode<'a>,
+    {
+        self.dyndb.put_reserve::<KC>(txn, key, data_size)
+    }
+
     /// Append the given key/data pair to the end of the database.
     ///
     /// This option allows fast bulk loading when keys are already known to be in the correct order.
@@ -1345,6 +1774,322 @@ impl<KC, DC> Database<KC, DC> {
         self.dyndb.append::<KC, DC>(txn, key, data)
     }
 
+    /// Insert a key-value pair in this database, with the given put flags.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{Database, Error, MdbError, PutFlags};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_database::<BEI32, Str>(&mut wtxn, Some("append-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put_with_flags(&mut wtxn, &13, "i-am-thirteen", PutFlags::APPEND)?;
+    /// db.put_with_flags(&mut wtxn, &27, "i-am-twenty-seven", PutFlags::APPEND)?;
+    ///
+    /// let ret = db.put_with_flags(&mut wtxn, &21, "i-am-21", PutFlags::APPEND);
+    /// assert!(matches!(ret, Err(Error::Mdb(MdbError::KeyExist))));
+    ///
+    /// let ret = db.put_with_flags(&mut wtxn, &13, "i-am-13", PutFlags::NO_OVERWRITE);
+    /// assert!(matches!(ret, Err(Error::Mdb(MdbError::KeyExist))));
+    ///
+    /// let ret = db.get(&mut wtxn, &13)?;
+    /// assert_eq!(ret, Some("i-am-thirteen"));
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn put_with_flags<'a>(
+        &self,
+        txn: &mut RwTxn,
+        key: &'a KC::EItem,
+        data: &'a DC::EItem,
+        flags: PutFlags,
+    ) -> Result<()>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
+    {
+        self.dyndb.put_with_flags::<KC, DC>(txn, key, data, flags)
+    }
+
+    /// Insert a key-value pair in this database only if the value differs from the stored one.
+    ///
+    /// The encoded value is compared byte for byte with the stored one, the data type doesn't have
+    /// to implement [`PartialEq`]. Skipping the identical writes avoids dirtying pages for nothing.
+    /// Returns `true` if the value was written.
+    ///
+    /// This method can't be used with a database created with the [`DatabaseFlags::DUP_SORT`] flag.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<BEI32, Str> = env.create_database(&mut wtxn, Some("put-if-changed-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// let written = db.put_if_changed(&mut wtxn, &42, "i-am-forty-two")?;
+    /// assert!(written);
+    ///
+    /// let written = db.put_if_changed(&mut wtxn, &42, "i-am-forty-two")?;
+    /// assert!(!written);
+    ///
+    /// let written = db.put_if_changed(&mut wtxn, &42, "i-am-the-answer")?;
+    /// assert!(written);
+    ///
+    /// let ret = db.get(&wtxn, &42)?;
+    /// assert_eq!(ret, Some("i-am-the-answer"));
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn put_if_changed<'a>(
+        &self,
+        txn: &mut RwTxn,
+        key: &'a KC::EItem,
+        data: &'a DC::EItem,
+    ) -> Result<bool>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
+    {
+        self.dyndb.put_if_changed::<KC, DC>(txn, key, data)
+    }
+
+    /// Insert all the given key-value pairs in this database, reusing a single write cursor.
+    ///
+    /// This is cheaper than calling [`Self::put`] in a loop when loading many entries.
+    /// It returns the number of entries that were written.
+    ///
+    /// If the database was created with the [`DatabaseFlags::DUP_SORT`] flag, the values
+    /// are added to the sorted duplicate values of the keys instead of replacing them.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<BEI32, Str> = env.create_database(&mut wtxn, Some("put-multi-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// let items = [(&42, "i-am-forty-two"), (&27, "i-am-twenty-seven"), (&13, "i-am-thirteen")];
+    /// let count = db.put_multi(&mut wtxn, items)?;
+    /// assert_eq!(count, 3);
+    ///
+    /// let ret = db.get(&wtxn, &27)?;
+    /// assert_eq!(ret, Some("i-am-twenty-seven"));
+    /// assert_eq!(db.len(&wtxn)?, 3);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn put_multi<'a, I>(&self, txn: &mut RwTxn, items: I) -> Result<usize>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
+        I: IntoIterator<Item = (&'a KC::EItem, &'a DC::EItem)>,
+    {
+        self.dyndb.put_multi::<KC, DC, I>(txn, items)
+    }
+
+    /// Insert all the given key-value pairs in this database with the given put flags,
+    /// reusing a single write cursor.
+    ///
+    /// When the entries are already sorted by key, [`PutFlags::APPEND`] makes the bulk
+    /// loading even faster, the first out of order key stops the insertion with a
+    /// [`MdbError::KeyExist`] error. It returns the number of entries that were written.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{Database, Error, MdbError, PutFlags};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<BEI32, Str> = env.create_database(&mut wtxn, Some("put-multi-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// let items = [(&13, "i-am-thirteen"), (&27, "i-am-twenty-seven"), (&42, "i-am-forty-two")];
+    /// let count = db.put_multi_with_flags(&mut wtxn, items, PutFlags::APPEND)?;
+    /// assert_eq!(count, 3);
+    ///
+    /// let items = [(&43, "i-am-forty-three"), (&21, "i-am-twenty-one")];
+    /// let ret = db.put_multi_with_flags(&mut wtxn, items, PutFlags::APPEND);
+    /// assert!(matches!(ret, Err(Error::Mdb(MdbError::KeyExist))));
+    ///
+    /// let ret = db.get(&wtxn, &43)?;
+    /// assert_eq!(ret, Some("i-am-forty-three"));
+    /// let ret = db.get(&wtxn, &21)?;
+    /// assert_eq!(ret, None);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn put_multi_with_flags<'a, I>(
+        &self,
+        txn: &mut RwTxn,
+        items: I,
+        flags: PutFlags,
+    ) -> Result<usize>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
+        I: IntoIterator<Item = (&'a KC::EItem, &'a DC::EItem)>,
+    {
+        self.dyndb.put_multi_with_flags::<KC, DC, I>(txn, items, flags)
+    }
+
+    /// Retrieves the value associated with a key or inserts the given default one if
+    /// the key doesn't exist yet, the key is only searched once.
+    ///
+    /// This method can't be used with a database created with the [`DatabaseFlags::DUP_SORT`] flag.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_database::<BEI32, Str>(&mut wtxn, Some("number-string"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
+    ///
+    /// let ret = db.get_or_put(&mut wtxn, &42, "i-am-the-default")?;
+    /// assert_eq!(ret, "i-am-forty-two");
+    ///
+    /// let ret = db.get_or_put(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// assert_eq!(ret, "i-am-twenty-seven");
+    ///
+    /// let ret = db.get(&wtxn, &27)?;
+    /// assert_eq!(ret, Some("i-am-twenty-seven"));
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_or_put<'a, 'txn>(
+        &self,
+        txn: &'txn mut RwTxn,
+        key: &'a KC::EItem,
+        data: &'a DC::EItem,
+    ) -> Result<DC::DItem>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a> + BytesDecode<'txn>,
+    {
+        self.dyndb.get_or_put::<KC, DC>(txn, key, data)
+    }
+
+    /// Retrieves the value associated with a key or inserts the one returned by the
+    /// given function if the key doesn't exist yet, the function is only called in this case.
+    ///
+    /// This method can't be used with a database created with the [`DatabaseFlags::DUP_SORT`] flag.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db = env.create_database::<BEI32, Str>(&mut wtxn, Some("number-string"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
+    ///
+    /// let ret = db.get_or_put_with(&mut wtxn, &42, || String::from("i-am-the-default"))?;
+    /// assert_eq!(ret, "i-am-forty-two");
+    ///
+    /// let ret = db.get_or_put_with(&mut wtxn, &27, || format!("i-am-{}", 27))?;
+    /// assert_eq!(ret, "i-am-27");
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_or_put_with<'a, 'txn, E, T, F>(
+        &self,
+        txn: &'txn mut RwTxn,
+        key: &'a KC::EItem,
+        default: F,
+    ) -> Result<DC::DItem>
+    where
+        KC: BytesEncode<'a>,
+        DC: for<'e> BytesEncode<'e, EItem = E> + BytesDecode<'txn>,
+        E: ?Sized + 'static,
+        T: Borrow<E>,
+        F: FnOnce() -> T,
+    {
+        self.dyndb.get_or_put_with::<KC, DC, E, T, F>(txn, key, default)
+    }
+
     /// Deletes a key-value pairs in this database.
     ///
     /// If the key does not exist, then `false` is returned.
@@ -1393,6 +2138,121 @@ impl<KC, DC> Database<KC, DC> {
         self.dyndb.delete::<KC>(txn, key)
     }
 
+    /// Moves the value of a key to another key of this database.
+    ///
+    /// The value is read, written under the new key and the old key is deleted, all of this
+    /// in the given transaction. If the new key already exists its value is overwritten.
+    /// Returns `false`, without writing anything, if the old key does not exist.
+    ///
+    /// This method can't be used with a database created with the [`DatabaseFlags::DUP_SORT`] flag,
+    /// an [`MdbError::Incompatible`] error is returned otherwise.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<BEI32, Str> = env.create_database(&mut wtxn, Some("move-key-i32"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, &27, "i-am-twenty-seven")?;
+    /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
+    ///
+    /// let moved = db.move_key(&mut wtxn, &27, &13)?;
+    /// assert!(moved);
+    /// assert_eq!(db.get(&wtxn, &27)?, None);
+    /// assert_eq!(db.get(&wtxn, &13)?, Some("i-am-twenty-seven"));
+    ///
+    /// // The value of an existing key is overwritten.
+    /// let moved = db.move_key(&mut wtxn, &13, &42)?;
+    /// assert!(moved);
+    /// assert_eq!(db.get(&wtxn, &42)?, Some("i-am-twenty-seven"));
+    ///
+    /// let moved = db.move_key(&mut wtxn, &13, &42)?;
+    /// assert!(!moved);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn move_key<'a>(
+        &self,
+        txn: &mut RwTxn,
+        from: &'a KC::EItem,
+        to: &'a KC::EItem,
+    ) -> Result<bool>
+    where
+        KC: BytesEncode<'a>,
+    {
+        self.dyndb.move_key::<KC>(txn, from, to)
+    }
+
+    /// Deletes a single duplicate value of a key in a database created with the
+    /// [`DatabaseFlags::DUP_SORT`] flag.
+    ///
+    /// If the key/value pair does not exist, then `false` is returned.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::{Database, DatabaseFlags};
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let flags = DatabaseFlags::DUP_SORT;
+    /// let db: Database<BEI32, Str> =
+    ///     env.create_database_with_flags(&mut wtxn, Some("dup-i32"), flags)?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, &35, "i-am-thirty-five")?;
+    /// db.put(&mut wtxn, &35, "i-am-also-thirty-five")?;
+    ///
+    /// let ret = db.delete_one(&mut wtxn, &35, "i-am-thirty-five")?;
+    /// assert_eq!(ret, true);
+    ///
+    /// let ret = db.delete_one(&mut wtxn, &35, "i-am-thirty-five")?;
+    /// assert_eq!(ret, false);
+    ///
+    /// let ret = db.get(&wtxn, &35)?;
+    /// assert_eq!(ret, Some("i-am-also-thirty-five"));
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn delete_one<'a>(
+        &self,
+        txn: &mut RwTxn,
+        key: &'a KC::EItem,
+        data: &'a DC::EItem,
+    ) -> Result<bool>
+    where
+        KC: BytesEncode<'a>,
+        DC: BytesEncode<'a>,
+    {
+        self.dyndb.delete_one::<KC, DC>(txn, key, data)
+    }
+
     /// Deletes a range of key-value pairs in this database.
     ///
     /// Perfer using [`clear`] instead of a call to this method with a full range ([`..`]).
@@ -1443,7 +2303,7 @@ impl<KC, DC> Database<KC, DC> {
     /// ```
     pub fn delete_range<'a, 'txn, R>(&self, txn: &'txn mut RwTxn, range: &'a R) -> Result<usize>
     where
-        KC: BytesEncode<'a> + BytesDecode<'txn>,
+        KC: BytesEncode<'a>,
         R: RangeBounds<KC::EItem>,
     {
         self.dyndb.delete_range::<KC, R>(txn, range)
@@ -1451,10 +2311,17 @@ impl<KC, DC> Database<KC, DC> {
 
     /// Deletes all key/value pairs in this database.
     ///
-    /// Perfer using this method instead of a call to [`delete_range`] with a full range ([`..`]).
+    /// The database is only emptied, not destroyed: it stays registered in the environment,
+    /// its flags are kept and this handle remains valid for subsequent writes, in this
+    /// transaction as in the next ones. The deletion is part of the transaction and is
+    /// discarded if the transaction is aborted.
+    /// Use [`delete_database`] to remove the database itself from the environment.
+    ///
+    /// Prefer using this method instead of a call to [`delete_range`] with a full range ([`..`]).
     ///
     /// [`delete_range`]: crate::Database::delete_range
     /// [`..`]: std::ops::RangeFull
+    /// [`delete_database`]: crate::Database::delete_database
     ///
     /// ```
     /// # use std::fs;
@@ -1486,6 +2353,10 @@ impl<KC, DC> Database<KC, DC> {
     /// let ret = db.is_empty(&wtxn)?;
     /// assert!(ret);
     ///
+    /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
+    /// let ret = db.len(&wtxn)?;
+    /// assert_eq!(ret, 1);
+    ///
     /// wtxn.commit()?;
     /// # Ok(()) }
     /// ```
@@ -1493,6 +2364,118 @@ impl<KC, DC> Database<KC, DC> {
         self.dyndb.clear(txn)
     }
 
+    /// Deletes this database from the environment, its entries are removed and its pages
+    /// are reclaimed, the name is then free to be used for a new database.
+    ///
+    /// This handle is consumed as it becomes invalid. The deletion is part of the transaction,
+    /// yet the handles are closed even if the transaction is aborted, the database can be
+    /// reopened then.
+    ///
+    /// The unnamed database can't be deleted, it is only emptied like with [`Self::clear`],
+    /// its handles stay valid and it can't be reopened with other types.
+    ///
+    /// # Safety
+    ///
+    /// The handles are [`Copy`] and LMDB gives the dbi of a deleted database to the next one
+    /// opened in this environment. The caller must ensure that no other handle to this named
+    /// database, i.e. a copy of this one, is used after this call, it would silently read and
+    /// write another database with the wrong types. No other thread must use this database
+    /// while it is deleted either, LMDB closes its handle.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    /// use heed::byteorder::BigEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type BEI32 = I32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<BEI32, Str> = env.create_database(&mut wtxn, Some("tenant-42"))?;
+    ///
+    /// db.put(&mut wtxn, &42, "i-am-forty-two")?;
+    /// db.put(&mut wtxn, &27, "i-am-twenty-seven")?;
+    ///
+    /// unsafe { db.delete_database(&mut wtxn)? };
+    /// wtxn.commit()?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// assert!(env.open_database::<BEI32, Str>(&rtxn, Some("tenant-42"))?.is_none());
+    /// # Ok(()) }
+    /// ```
+    pub unsafe fn delete_database(self, txn: &mut RwTxn) -> Result<()> {
+        self.dyndb.delete_database(txn)
+    }
+
+    /// Sets a custom comparator to order the keys of this database.
+    ///
+    /// LMDB doesn't store the comparator, it must be set before any data is read or written
+    /// in the database, and again each time the environment is opened, before any other use
+    /// of the database. Using a database with another ordering than the one that was used
+    /// to write it will make LMDB misplace and miss the keys.
+    ///
+    /// Trying to set a different comparator on a database that already contains entries
+    /// returns an [`Error::ComparatorChanged`] if another one was set in this environment,
+    /// setting the same comparator again is a no-op.
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use std::path::Path;
+    /// # use heed::EnvOpenOptions;
+    /// use std::cmp::Ordering;
+    /// use heed::{Comparator, Database};
+    /// use heed::types::*;
+    ///
+    /// /// Orders version strings like `1.10.0` after `1.9.3`.
+    /// enum Version {}
+    ///
+    /// impl Comparator for Version {
+    ///     fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    ///         let parse = |bytes| {
+    ///             let s = std::str::from_utf8(bytes).unwrap_or_default();
+    ///             s.split('.').map(|n| n.parse().unwrap_or(0)).collect::<Vec<u64>>()
+    ///         };
+    ///         parse(a).cmp(&parse(b)).then_with(|| a.cmp(b))
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<Str, Unit> = env.create_database(&mut wtxn, Some("versions"))?;
+    /// db.set_compare::<Version>(&mut wtxn)?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, "1.10.0", &())?;
+    /// db.put(&mut wtxn, "1.2.0", &())?;
+    /// db.put(&mut wtxn, "1.9.3", &())?;
+    ///
+    /// let mut iter = db.iter(&wtxn)?;
+    /// assert_eq!(iter.next().transpose()?, Some(("1.2.0", ())));
+    /// assert_eq!(iter.next().transpose()?, Some(("1.9.3", ())));
+    /// assert_eq!(iter.next().transpose()?, Some(("1.10.0", ())));
+    /// assert_eq!(iter.next().transpose()?, None);
+    ///
+    /// drop(iter);
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_compare<C: Comparator + 'static>(&self, txn: &mut RwTxn) -> Result<()> {
+        self.dyndb.set_compare::<C>(txn)
+    }
+
     /// Change the codec types of this uniform database, specifying the codecs.
     ///
     /// # Safety
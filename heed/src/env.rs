@@ -1,6 +1,6 @@
 use std::any::TypeId;
 use std::collections::hash_map::{Entry, HashMap};
-use std::ffi::{c_void, CString};
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
 use std::fs::{File, Metadata};
 use std::io::ErrorKind::NotFound;
 #[cfg(unix)]
@@ -9,6 +9,7 @@ use std::os::unix::{
     io::{AsRawFd, BorrowedFd, RawFd},
 };
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 #[cfg(windows)]
@@ -24,7 +25,8 @@ use synchronoise::event::SignalEvent;
 use crate::mdb::error::mdb_result;
 use crate::mdb::ffi;
 use crate::{
-    assert_eq_env_txn, Database, Error, Flag, PolyDatabase, Result, RoCursor, RoTxn, RwTxn,
+    assert_eq_env_txn, Database, DatabaseFlags, EnvFlags, Error, Flag, IntegerKey, MdbError,
+    PolyDatabase, Result, RoCursor, RoTxn, RwTxn, SendableRoTxn,
 };
 
 /// The list of opened environments, the value is an optional environment, it is None
@@ -34,6 +36,14 @@ use crate::{
 /// Trying to open a None marked environment returns an error to the user trying to open it.
 static OPENED_ENV: Lazy<RwLock<HashMap<PathBuf, EnvEntry>>> = Lazy::new(RwLock::default);
 
+/// The maximum number of named databases that can be asked to LMDB, it internally
+/// adds its two core databases (the free list and the main one) to the given number.
+const MAX_DBS: u32 = u32::MAX - 2;
+
+/// The maximum number of times [`Env::with_write_txn`] retries a transaction
+/// that failed because the map was full.
+pub const WRITE_TXN_RETRIES: usize = 8;
+
 struct EnvEntry {
     env: Option<Env>,
     signal_event: Arc<SignalEvent>,
@@ -102,13 +112,20 @@ pub struct EnvOpenOptions {
     map_size: Option<usize>,
     max_readers: Option<u32>,
     max_dbs: Option<u32>,
+    auto_resize: Option<f64>,
     flags: u32, // LMDB flags
 }
 
 impl EnvOpenOptions {
     /// Creates a blank new set of options ready for configuration.
     pub fn new() -> EnvOpenOptions {
-        EnvOpenOptions { map_size: None, max_readers: None, max_dbs: None, flags: 0 }
+        EnvOpenOptions {
+            map_size: None,
+            max_readers: None,
+            max_dbs: None,
+            auto_resize: None,
+            flags: 0,
+        }
     }
 
     /// Set the size of the memory map to use for this environment.
@@ -118,17 +135,60 @@ impl EnvOpenOptions {
     }
 
     /// Set the maximum number of threads/reader slots for the environment.
+    ///
+    /// LMDB defaults to 126 slots, a read transaction taken when they are all used fails with
+    /// [`MdbError::ReadersFull`](crate::MdbError::ReadersFull). The value is only applied when
+    /// the environment is opened, opening an already opened environment with another value
+    /// returns an [`Error::BadOpenOptions`] error. It can be checked with
+    /// [`EnvInfo::maximum_number_of_readers`].
+    ///
+    /// ```
+    /// use heed::{EnvOpenOptions, Error};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// let env = EnvOpenOptions::new().max_readers(500).open(dir.path())?;
+    /// assert_eq!(env.info()?.maximum_number_of_readers, 500);
+    ///
+    /// let result = EnvOpenOptions::new().max_readers(1000).open(dir.path());
+    /// assert!(matches!(result, Err(Error::BadOpenOptions { .. })));
+    /// # Ok(()) }
+    /// ```
     pub fn max_readers(&mut self, readers: u32) -> &mut Self {
         self.max_readers = Some(readers);
         self
     }
 
     /// Set the maximum number of named databases for the environment.
+    ///
+    /// This function is only needed if multiple databases will be used in the environment,
+    /// by default no named database can be opened. Opening or creating more databases than
+    /// the given number returns an [`Error::DbsFull`] error.
+    ///
+    /// LMDB allocates an internal slot per database (`MDB_MAXDBS`) and walks through them
+    /// each time a database is opened, a moderate number is therefore cheap but a very
+    /// large one is not free.
     pub fn max_dbs(&mut self, dbs: u32) -> &mut Self {
         self.max_dbs = Some(dbs);
         self
     }
 
+    /// Grow the memory map by the given factor when a write fails because the map is full.
+    ///
+    /// When a write operation returns `MDB_MAP_FULL`, an [`Error::MapResized`] error is returned
+    /// instead and the map is grown as soon as the write transaction is committed or aborted,
+    /// the caller can then retry the whole transaction with a new one.
+    ///
+    /// Growing the map can move it to another address, the resize is therefore postponed
+    /// while other read or write transactions of this process are alive, it is done when
+    /// the last one ends and the values borrowed from them stay valid.
+    ///
+    /// The factor must be greater than `1.0`.
+    pub fn auto_resize(&mut self, factor: f64) -> &mut Self {
+        self.auto_resize = Some(factor);
+        self
+    }
+
     /// Set one or [more LMDB flags](http://www.lmdb.tech/doc/group__mdb__env.html).
     /// ```
     /// use std::fs;
@@ -176,6 +236,81 @@ impl EnvOpenOptions {
         self
     }
 
+    /// Open the environment in read-only mode without using the lock file.
+    ///
+    /// It sets the [`EnvFlags::READ_ONLY`] and [`EnvFlags::NO_LOCK`] flags, the environment can
+    /// then be opened from a read-only storage where LMDB can't create its lock file.
+    /// Creating a write transaction returns an [`Error::EnvReadOnly`] error.
+    ///
+    /// ```
+    /// use heed::{Database, EnvOpenOptions, Error};
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dir = tempfile::tempdir()?;
+    /// let env = EnvOpenOptions::new().max_dbs(10).open(dir.path())?;
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<Str, Str> = env.create_database(&mut wtxn, Some("snapshot"))?;
+    /// db.put(&mut wtxn, "hello", "world")?;
+    /// wtxn.commit()?;
+    /// env.prepare_for_closing().wait();
+    ///
+    /// let mut env_builder = EnvOpenOptions::new();
+    /// env_builder.max_dbs(10);
+    /// unsafe { env_builder.read_only() };
+    /// let env = env_builder.open(dir.path())?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// let db: Database<Str, Str> = env.open_database(&rtxn, Some("snapshot"))?.unwrap();
+    /// assert_eq!(db.get(&rtxn, "hello")?, Some("world"));
+    ///
+    /// assert!(matches!(env.write_txn(), Err(Error::EnvReadOnly)));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// No lock is taken, no other process must write in this environment while it is opened.
+    pub unsafe fn read_only(&mut self) -> &mut Self {
+        self.flags(EnvFlags::READ_ONLY | EnvFlags::NO_LOCK)
+    }
+
+    /// Set a set of [LMDB flags](http://www.lmdb.tech/doc/group__mdb__env.html),
+    /// they are added to the ones already set.
+    ///
+    /// ```
+    /// use heed::{EnvFlags, EnvOpenOptions, Database, Flag};
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dir = tempfile::tempdir()?;
+    /// let mut env_builder = EnvOpenOptions::new();
+    /// unsafe { env_builder.flags(EnvFlags::WRITE_MAP | EnvFlags::NO_META_SYNC) };
+    /// let env = env_builder.open(dir.path())?;
+    ///
+    /// assert!(env.contains_flag(Flag::WriteMap)?);
+    /// assert!(env.contains_flag(Flag::NoMetaSync)?);
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<Str, Str> = env.create_database(&mut wtxn, None)?;
+    /// db.put(&mut wtxn, "hello", "world")?;
+    /// wtxn.commit()?;
+    ///
+    /// // Force the OS to flush the buffers, the metadata flush is omitted on commit.
+    /// env.force_sync()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Some flags trade the durability of the commits for speed, like [`EnvFlags::NO_SYNC`],
+    /// [`EnvFlags::NO_META_SYNC`] and [`EnvFlags::MAP_ASYNC`], others like [`EnvFlags::NO_LOCK`]
+    /// or [`EnvFlags::WRITE_MAP`] require the caller to uphold guarantees that LMDB can't check.
+    pub unsafe fn flags(&mut self, flags: EnvFlags) -> &mut Self {
+        self.flags |= flags.bits();
+        self
+    }
+
     /// Open an environment that will be located at the specified path.
     pub fn open<P: AsRef<Path>>(&self, path: P) -> Result<Env> {
         let mut lock = OPENED_ENV.write().unwrap();
@@ -228,11 +363,32 @@ impl EnvOpenOptions {
                         mdb_result(ffi::mdb_env_set_mapsize(env, size))?;
                     }
 
+                    if let Some(factor) = self.auto_resize {
+                        if factor.is_nan() || factor <= 1.0 {
+                            let msg =
+                                format!("auto resize factor ({}) must be greater than 1", factor);
+                            return Err(Error::Io(io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                msg,
+                            )));
+                        }
+                    }
+
                     if let Some(readers) = self.max_readers {
                         mdb_result(ffi::mdb_env_set_maxreaders(env, readers))?;
                     }
 
                     if let Some(dbs) = self.max_dbs {
+                        if dbs > MAX_DBS {
+                            let msg = format!(
+                                "the maximum number of databases ({}) must not exceed {}",
+                                dbs, MAX_DBS
+                            );
+                            return Err(Error::Io(io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                msg,
+                            )));
+                        }
                         mdb_result(ffi::mdb_env_set_maxdbs(env, dbs))?;
                     }
 
@@ -254,7 +410,12 @@ impl EnvOpenOptions {
                             let inner = EnvInner {
                                 env,
                                 dbi_open_mutex: sync::Mutex::default(),
+                                comparators: sync::Mutex::default(),
                                 path: path.clone(),
+                                max_dbs: self.max_dbs.unwrap_or(0),
+                                auto_resize: self.auto_resize,
+                                resize_pending: AtomicBool::new(false),
+                                live_txns: sync::Mutex::new(0),
                             };
                             let env = Env(Arc::new(inner));
                             let cache_entry = EnvEntry {
@@ -288,7 +449,16 @@ pub struct Env(Arc<EnvInner>);
 
 impl fmt::Debug for Env {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let EnvInner { env: _, dbi_open_mutex: _, path } = self.0.as_ref();
+        let EnvInner {
+            env: _,
+            dbi_open_mutex: _,
+            comparators: _,
+            path,
+            max_dbs: _,
+            auto_resize: _,
+            resize_pending: _,
+            live_txns: _,
+        } = self.0.as_ref();
         f.debug_struct("Env").field("path", &path.display()).finish_non_exhaustive()
     }
 }
@@ -296,7 +466,14 @@ impl fmt::Debug for Env {
 struct EnvInner {
     env: *mut ffi::MDB_env,
     dbi_open_mutex: sync::Mutex<HashMap<u32, Option<(TypeId, TypeId)>>>,
+    comparators: sync::Mutex<HashMap<u32, TypeId>>,
     path: PathBuf,
+    max_dbs: u32,
+    auto_resize: Option<f64>,
+    resize_pending: AtomicBool,
+    /// The number of transactions of this process that are alive, the map is only resized
+    /// when there is none and no transaction can begin while it is.
+    live_txns: sync::Mutex<usize>,
 }
 
 unsafe impl Send for EnvInner {}
@@ -379,19 +556,68 @@ impl Env {
     }
 
     /// Returns some basic informations about this environment.
-    pub fn info(&self) -> EnvInfo {
+    pub fn info(&self) -> Result<EnvInfo> {
         let mut raw_info = mem::MaybeUninit::uninit();
-        unsafe { ffi::mdb_env_info(self.0.env, raw_info.as_mut_ptr()) };
+        unsafe { mdb_result(ffi::mdb_env_info(self.0.env, raw_info.as_mut_ptr()))? };
         let raw_info = unsafe { raw_info.assume_init() };
 
-        EnvInfo {
+        Ok(EnvInfo {
             map_addr: raw_info.me_mapaddr,
             map_size: raw_info.me_mapsize,
             last_page_number: raw_info.me_last_pgno,
             last_txn_id: raw_info.me_last_txnid,
             maximum_number_of_readers: raw_info.me_maxreaders,
             number_of_readers: raw_info.me_numreaders,
-        }
+        })
+    }
+
+    /// Returns the statistics of the main database of this environment,
+    /// i.e. the one listing the named databases.
+    pub fn stat(&self) -> Result<EnvStat> {
+        let mut raw_stat = mem::MaybeUninit::uninit();
+        unsafe { mdb_result(ffi::mdb_env_stat(self.0.env, raw_stat.as_mut_ptr()))? };
+        let raw_stat = unsafe { raw_stat.assume_init() };
+
+        Ok(EnvStat {
+            page_size: raw_stat.ms_psize,
+            depth: raw_stat.ms_depth,
+            branch_pages: raw_stat.ms_branch_pages,
+            leaf_pages: raw_stat.ms_leaf_pages,
+            overflow_pages: raw_stat.ms_overflow_pages,
+            entries: raw_stat.ms_entries,
+        })
+    }
+
+    /// Returns the size of the pages that are in use in the data file, free or not.
+    ///
+    /// It is computed from the last used page number and is the size the data file would have
+    /// after a copy without compaction. Unlike the [`EnvInfo::map_size`], which is only reserved
+    /// address space, it is the amount of the map that is actually filled: when it gets close to
+    /// the map size the environment needs to be resized, when it is much bigger than the
+    /// [`Env::non_free_pages_size`] compacting the environment reclaims the free pages.
+    ///
+    /// ```
+    /// use heed::{Database, EnvOpenOptions};
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// let env = EnvOpenOptions::new().map_size(10 * 1024 * 1024).open(dir.path())?;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<Str, Str> = env.create_database(&mut wtxn, None)?;
+    /// db.put(&mut wtxn, "hello", "world")?;
+    /// wtxn.commit()?;
+    ///
+    /// let data_size = env.data_size()?;
+    /// assert!(env.non_free_pages_size()? <= data_size);
+    /// assert!(data_size <= env.info()?.map_size as u64);
+    /// # Ok(()) }
+    /// ```
+    pub fn data_size(&self) -> Result<u64> {
+        let info = self.info()?;
+        let stat = self.stat()?;
+        Ok((info.last_page_number as u64 + 1) * stat.page_size as u64)
     }
 
     /// Returns the size used by all the databases in the environment without the free pages.
@@ -442,7 +668,32 @@ impl Env {
 
     /// Opens a typed database that already exists in this environment.
     ///
+    /// Unlike [`Env::create_database`] the database is never created, `None` is returned
+    /// when no database with this name exists, this way a typo in a name can't silently
+    /// materialize an empty database.
+    ///
     /// If the database was previously opened in this program run, types will be checked.
+    /// An [`Error::DbsFull`] is returned when the [`EnvOpenOptions::max_dbs`] limit is reached.
+    ///
+    /// ```
+    /// use heed::{Database, EnvOpenOptions};
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// let mut wtxn = env.write_txn()?;
+    /// let _: Database<Str, Str> = env.create_database(&mut wtxn, Some("users"))?;
+    /// wtxn.commit()?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// assert!(env.open_database::<Str, Str>(&rtxn, Some("users"))?.is_some());
+    /// assert!(env.open_database::<Str, Str>(&rtxn, Some("usres"))?.is_none());
+    /// # Ok(()) }
+    /// ```
     ///
     /// ## Important Information
     ///
@@ -461,7 +712,7 @@ impl Env {
         assert_eq_env_txn!(self, rtxn);
 
         let types = (TypeId::of::<KC>(), TypeId::of::<DC>());
-        match self.raw_init_database(rtxn.txn, name, Some(types), false) {
+        match self.raw_init_database(rtxn.txn, name, Some(types), DatabaseFlags::empty(), false) {
             Ok(dbi) => Ok(Some(Database::new(self.env_mut_ptr() as _, dbi))),
             Err(Error::Mdb(e)) if e.not_found() => Ok(None),
             Err(e) => Err(e),
@@ -471,6 +722,7 @@ impl Env {
     /// Opens an untyped database that already exists in this environment.
     ///
     /// If the database was previously opened as a typed one, an error will be returned.
+    /// An [`Error::DbsFull`] is returned when the [`EnvOpenOptions::max_dbs`] limit is reached.
     ///
     /// ## Important Information
     ///
@@ -484,16 +736,68 @@ impl Env {
     ) -> Result<Option<PolyDatabase>> {
         assert_eq_env_txn!(self, rtxn);
 
-        match self.raw_init_database(rtxn.txn, name, None, false) {
+        match self.raw_init_database(rtxn.txn, name, None, DatabaseFlags::empty(), false) {
             Ok(dbi) => Ok(Some(PolyDatabase::new(self.env_mut_ptr() as _, dbi))),
             Err(Error::Mdb(e)) if e.not_found() => Ok(None),
             Err(e) => Err(e),
         }
     }
 
+    /// Creates a typed database whose keys are native endian integers,
+    /// it can already exist in this environment.
+    ///
+    /// The database is created with the [`DatabaseFlags::INTEGER_KEY`] flag, LMDB compares the
+    /// keys as integers and the iterators and ranges are returned in the numerical order.
+    /// The key codec must be one of the [`IntegerKey`]s, e.g. `U32<NativeEndian>`.
+    ///
+    /// ```
+    /// use heed::{Database, EnvOpenOptions};
+    /// use heed::types::*;
+    /// use heed::byteorder::NativeEndian;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// type NEU32 = U32<NativeEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<NEU32, Str> = env.create_integer_database(&mut wtxn, Some("integers"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// db.put(&mut wtxn, &256, "two-hundred-fifty-six")?;
+    /// db.put(&mut wtxn, &1, "one")?;
+    /// db.put(&mut wtxn, &255, "two-hundred-fifty-five")?;
+    ///
+    /// let mut iter = db.range(&wtxn, &(1..=256))?;
+    /// assert_eq!(iter.next().transpose()?, Some((1, "one")));
+    /// assert_eq!(iter.next().transpose()?, Some((255, "two-hundred-fifty-five")));
+    /// assert_eq!(iter.next().transpose()?, Some((256, "two-hundred-fifty-six")));
+    /// assert_eq!(iter.next().transpose()?, None);
+    ///
+    /// drop(iter);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn create_integer_database<KC, DC>(
+        &self,
+        wtxn: &mut RwTxn,
+        name: Option<&str>,
+    ) -> Result<Database<KC, DC>>
+    where
+        KC: IntegerKey + 'static,
+        DC: 'static,
+    {
+        self.create_database_with_flags(wtxn, name, DatabaseFlags::INTEGER_KEY)
+    }
+
     /// Creates a typed database that can already exist in this environment.
     ///
     /// If the database was previously opened in this program run, types will be checked.
+    /// An [`Error::DbsFull`] is returned when the [`EnvOpenOptions::max_dbs`] limit is reached.
     ///
     /// ## Important Information
     ///
@@ -505,6 +809,27 @@ impl Env {
         wtxn: &mut RwTxn,
         name: Option<&str>,
     ) -> Result<Database<KC, DC>>
+    where
+        KC: 'static,
+        DC: 'static,
+    {
+        self.create_database_with_flags(wtxn, name, DatabaseFlags::empty())
+    }
+
+    /// Creates a typed database with the given flags, it can already exist in this environment.
+    ///
+    /// The flags must be the same every time the database is opened, e.g. a database
+    /// created with [`DatabaseFlags::DUP_SORT`] stores the duplicate values of a key
+    /// instead of overwriting them.
+    ///
+    /// If the database was previously opened in this program run, types will be checked.
+    /// An [`Error::DbsFull`] is returned when the [`EnvOpenOptions::max_dbs`] limit is reached.
+    pub fn create_database_with_flags<KC, DC>(
+        &self,
+        wtxn: &mut RwTxn,
+        name: Option<&str>,
+        flags: DatabaseFlags,
+    ) -> Result<Database<KC, DC>>
     where
         KC: 'static,
         DC: 'static,
@@ -512,7 +837,7 @@ impl Env {
         assert_eq_env_txn!(self, wtxn);
 
         let types = (TypeId::of::<KC>(), TypeId::of::<DC>());
-        match self.raw_init_database(wtxn.txn.txn, name, Some(types), true) {
+        match self.raw_init_database(wtxn.txn.txn, name, Some(types), flags, true) {
             Ok(dbi) => Ok(Database::new(self.env_mut_ptr() as _, dbi)),
             Err(e) => Err(e),
         }
@@ -521,6 +846,7 @@ impl Env {
     /// Creates a typed database that can already exist in this environment.
     ///
     /// If the database was previously opened as a typed one, an error will be returned.
+    /// An [`Error::DbsFull`] is returned when the [`EnvOpenOptions::max_dbs`] limit is reached.
     ///
     /// ## Important Information
     ///
@@ -531,15 +857,137 @@ impl Env {
         &self,
         wtxn: &mut RwTxn,
         name: Option<&str>,
+    ) -> Result<PolyDatabase> {
+        self.create_poly_database_with_flags(wtxn, name, DatabaseFlags::empty())
+    }
+
+    /// Creates an untyped database with the given flags, it can already exist in this environment.
+    ///
+    /// The flags must be the same every time the database is opened.
+    ///
+    /// If the database was previously opened as a typed one, an error will be returned.
+    /// An [`Error::DbsFull`] is returned when the [`EnvOpenOptions::max_dbs`] limit is reached.
+    pub fn create_poly_database_with_flags(
+        &self,
+        wtxn: &mut RwTxn,
+        name: Option<&str>,
+        flags: DatabaseFlags,
     ) -> Result<PolyDatabase> {
         assert_eq_env_txn!(self, wtxn);
 
-        match self.raw_init_database(wtxn.txn.txn, name, None, true) {
+        match self.raw_init_database(wtxn.txn.txn, name, None, flags, true) {
             Ok(dbi) => Ok(PolyDatabase::new(self.env_mut_ptr() as _, dbi)),
             Err(e) => Err(e),
         }
     }
 
+    /// Exchanges the entries of two named databases in the given write transaction,
+    /// readers see both databases swapped once it is committed and never a part of it.
+    ///
+    /// LMDB can't rename a database, the entries are therefore copied: the ones of the
+    /// database with the fewest entries are first read in memory, the other database is
+    /// then copied into it and it finally receives the entries read in memory. The database
+    /// handles keep pointing to the same names and so see the swapped entries.
+    ///
+    /// Both databases must exist, an [`MdbError::NotFound`] error is returned otherwise,
+    /// and must have been created with the same flags and comparator, an
+    /// [`MdbError::Incompatible`] error is returned otherwise.
+    ///
+    /// ```
+    /// use heed::{Database, EnvOpenOptions};
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// let mut wtxn = env.write_txn()?;
+    /// let index: Database<Str, Str> = env.create_database(&mut wtxn, Some("idx"))?;
+    /// let new_index: Database<Str, Str> = env.create_database(&mut wtxn, Some("idx-new"))?;
+    /// index.put(&mut wtxn, "word", "old-posting")?;
+    /// new_index.put(&mut wtxn, "word", "new-posting")?;
+    /// new_index.put(&mut wtxn, "other", "new-posting")?;
+    /// wtxn.commit()?;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// env.swap_databases(&mut wtxn, "idx", "idx-new")?;
+    /// new_index.clear(&mut wtxn)?;
+    /// wtxn.commit()?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// assert_eq!(index.get(&rtxn, "word")?, Some("new-posting"));
+    /// assert_eq!(index.len(&rtxn)?, 2);
+    /// assert!(new_index.is_empty(&rtxn)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn swap_databases(&self, wtxn: &mut RwTxn, a: &str, b: &str) -> Result<()> {
+        assert_eq_env_txn!(self, wtxn);
+
+        let raw_txn = wtxn.txn.txn;
+        let open = |name| match self.raw_open_dbi(raw_txn, Some(name), 0) {
+            Ok(dbi) => Ok(dbi),
+            Err(MdbError::DbsFull) => Err(Error::DbsFull { max_dbs: self.0.max_dbs }),
+            Err(e) => Err(Error::from(e)),
+        };
+        let (a, b) = (open(a)?, open(b)?);
+        if a == b {
+            return Ok(());
+        }
+
+        let (mut a_flags, mut b_flags) = (0, 0);
+        unsafe {
+            mdb_result(ffi::mdb_dbi_flags(raw_txn, a, &mut a_flags))?;
+            mdb_result(ffi::mdb_dbi_flags(raw_txn, b, &mut b_flags))?;
+        }
+        let comparators = self.comparators();
+        let same_comparator = comparators.get(&a) == comparators.get(&b);
+        drop(comparators);
+        if a_flags != b_flags || !same_comparator {
+            return Err(MdbError::Incompatible.into());
+        }
+
+        let entries = |dbi| -> Result<usize> {
+            let mut stat = mem::MaybeUninit::uninit();
+            unsafe { mdb_result(ffi::mdb_stat(raw_txn, dbi, stat.as_mut_ptr()))? };
+            Ok(unsafe { stat.assume_init() }.ms_entries)
+        };
+        let (small, large) = if entries(a)? <= entries(b)? { (a, b) } else { (b, a) };
+
+        let mut buffer = Vec::new();
+        let mut cursor = RoCursor::new(wtxn, small)?;
+        while let Some((key, data)) = cursor.move_on_next()? {
+            buffer.push((key.to_vec(), data.to_vec()));
+        }
+        drop(cursor);
+
+        let clear = |dbi| unsafe {
+            mdb_result(ffi::mdb_drop(raw_txn, dbi, 0)).map_err(|e| wtxn.write_error(e))
+        };
+        let put = |dbi, key: &[u8], data: &[u8]| unsafe {
+            let mut key_val = crate::into_val(key);
+            let mut data_val = crate::into_val(data);
+            mdb_result(ffi::mdb_put(raw_txn, dbi, &mut key_val, &mut data_val, 0))
+                .map_err(|e| wtxn.write_error(e))
+        };
+
+        // Writing in a database doesn't move the cursors of the other ones.
+        clear(small)?;
+        let mut cursor = RoCursor::new(wtxn, large)?;
+        while let Some((key, data)) = cursor.move_on_next()? {
+            put(small, key, data)?;
+        }
+        drop(cursor);
+
+        clear(large)?;
+        for (key, data) in &buffer {
+            put(large, key, data)?;
+        }
+
+        Ok(())
+    }
+
     fn raw_open_dbi(
         &self,
         raw_txn: *mut ffi::MDB_txn,
@@ -565,11 +1013,12 @@ impl Env {
         raw_txn: *mut ffi::MDB_txn,
         name: Option<&str>,
         types: Option<(TypeId, TypeId)>,
+        flags: DatabaseFlags,
         create: bool,
     ) -> Result<u32> {
         let mut lock = self.0.dbi_open_mutex.lock().unwrap();
 
-        let flags = if create { ffi::MDB_CREATE } else { 0 };
+        let flags = if create { flags.bits() | ffi::MDB_CREATE } else { flags.bits() };
         match self.raw_open_dbi(raw_txn, name, flags) {
             Ok(dbi) => {
                 let old_types = lock.entry(dbi).or_insert(types);
@@ -579,6 +1028,7 @@ impl Env {
                     Err(Error::InvalidDatabaseTyping)
                 }
             }
+            Err(MdbError::DbsFull) => Err(Error::DbsFull { max_dbs: self.0.max_dbs }),
             Err(e) => Err(e.into()),
         }
     }
@@ -588,6 +1038,54 @@ impl Env {
         RwTxn::new(self)
     }
 
+    /// Runs the given closure in a new write transaction and commits it.
+    ///
+    /// If the closure or the commit returns an error the transaction is aborted and the
+    /// error is returned. The only error that is retried, with a new transaction, is the
+    /// [`Error::MapResized`] one returned when the map was full and is being grown by the
+    /// [`EnvOpenOptions::auto_resize`] mode, at most [`WRITE_TXN_RETRIES`] times. Note that
+    /// LMDB writers never conflict with readers, there is nothing else to retry.
+    ///
+    /// The closure can therefore be called multiple times and must not rely on side effects
+    /// of a previous run that was aborted.
+    ///
+    /// ```
+    /// use heed::{Database, EnvOpenOptions};
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// let db = env.with_write_txn(|wtxn| {
+    ///     let db: Database<Str, BEU64> = env.create_database(wtxn, Some("counters"))?;
+    ///     db.put(wtxn, "visits", &1)?;
+    ///     Ok(db)
+    /// })?;
+    ///
+    /// let rtxn = env.read_txn()?;
+    /// assert_eq!(db.get(&rtxn, "visits")?, Some(1));
+    /// # Ok(()) }
+    /// ```
+    pub fn with_write_txn<F, T>(&self, mut f: F) -> Result<T>
+    where
+        F: FnMut(&mut RwTxn) -> Result<T>,
+    {
+        let mut retries = 0;
+        loop {
+            let mut wtxn = self.write_txn()?;
+            let result = f(&mut wtxn).and_then(|value| wtxn.commit().map(|()| value));
+            match result {
+                // The aborted transaction has been dropped, the map is grown now
+                // unless other transactions are still alive.
+                Err(Error::MapResized) if retries < WRITE_TXN_RETRIES => retries += 1,
+                result => return result,
+            }
+        }
+    }
+
     /// Create a nested transaction with read and write access for use with the environment.
     ///
     /// The new transaction will be a nested transaction, with the transaction indicated by parent
@@ -604,10 +1102,51 @@ impl Env {
         RoTxn::new(self)
     }
 
+    /// Create a transaction with read-only access that can be sent to another thread.
+    ///
+    /// LMDB only allows it when the environment is opened with the [`EnvFlags::NO_TLS`] flag,
+    /// an [`Error::TlsEnabled`] error is returned otherwise.
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use heed::{Database, EnvFlags, EnvOpenOptions};
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// let mut options = EnvOpenOptions::new();
+    /// unsafe { options.flags(EnvFlags::NO_TLS) };
+    /// let env = options.open(dir.path())?;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<Str, Str> = env.create_database(&mut wtxn, None)?;
+    /// db.put(&mut wtxn, "hello", "world")?;
+    /// wtxn.commit()?;
+    ///
+    /// let rtxn = env.read_txn_sendable()?;
+    /// let value = thread::scope(|s| {
+    ///     s.spawn(move || db.get(&rtxn, "hello").map(|v| v.map(String::from))).join().unwrap()
+    /// })?;
+    /// assert_eq!(value.as_deref(), Some("world"));
+    /// # Ok(()) }
+    /// ```
+    pub fn read_txn_sendable(&self) -> Result<SendableRoTxn<'_>> {
+        SendableRoTxn::new(self)
+    }
+
     /// Copy an LMDB environment to the specified path, with options.
     ///
     /// This function may be used to make a backup of an existing environment.
     /// No lockfile is created, since it gets recreated at need.
+    ///
+    /// The copy is done in a read-only transaction, it can therefore be made while the environment
+    /// is being used by readers and writers. The file at the given path must not exist yet,
+    /// it is returned opened in read mode and can be opened as a standalone environment,
+    /// either by placing it as the `data.mdb` of a directory or by using the [`Flag::NoSubDir`] flag.
+    ///
+    /// Note that the copy can't be made from a thread that already holds a read transaction
+    /// on this environment, unless it was opened with the [`Flag::NoTls`] flag.
     pub fn copy_to_file<P: AsRef<Path>>(&self, path: P, option: CompactionOption) -> Result<File> {
         let file = File::options().create_new(true).write(true).open(&path)?;
         let fd = get_file_fd(&file);
@@ -672,6 +1211,10 @@ impl Env {
 
     /// Check for stale entries in the reader lock table and clear them.
     ///
+    /// A process that crashed while reading leaves its slots behind, once all of them are
+    /// taken new read transactions fail with [`MdbError::ReadersFull`]. Calling this function
+    /// from time to time in long running processes reclaims them.
+    ///
     /// Returns the number of stale readers cleared.
     pub fn clear_stale_readers(&self) -> Result<usize> {
         let mut dead: i32 = 0;
@@ -681,6 +1224,105 @@ impl Env {
         Ok(dead as usize)
     }
 
+    /// Returns the entries of the reader lock table, it is meant for diagnostics.
+    ///
+    /// A reader slot is held by a thread of a process, possibly a dead one that
+    /// [`Env::clear_stale_readers`] can reclaim.
+    pub fn reader_list(&self) -> Result<Vec<ReaderInfo>> {
+        extern "C" fn push_reader(msg: *const c_char, ctx: *mut c_void) -> c_int {
+            // safety: LMDB gives us back the vector we gave it and a nul-terminated line.
+            let readers = unsafe { &mut *(ctx as *mut Vec<ReaderInfo>) };
+            let line = unsafe { CStr::from_ptr(msg) };
+            // The header and the "no active readers" lines are not parsed.
+            if let Some(reader) = line.to_str().ok().and_then(ReaderInfo::from_line) {
+                readers.push(reader);
+            }
+            0
+        }
+
+        let mut readers = Vec::new();
+        let ctx = &mut readers as *mut Vec<ReaderInfo> as *mut c_void;
+        unsafe { mdb_result(ffi::mdb_reader_list(self.0.env, Some(push_reader), ctx))? }
+
+        Ok(readers)
+    }
+
+    /// Returns the comparators that were set on the databases of this environment,
+    /// identified by their dbi.
+    pub(crate) fn comparators(&self) -> sync::MutexGuard<'_, HashMap<u32, TypeId>> {
+        self.0.comparators.lock().unwrap()
+    }
+
+    /// Returns the dbis of all the databases opened in this environment.
+    pub(crate) fn opened_dbis(&self) -> Vec<u32> {
+        self.0.dbi_open_mutex.lock().unwrap().keys().copied().collect()
+    }
+
+    /// Forgets the types and the comparator associated to a dbi that LMDB closed.
+    pub(crate) fn forget_database(&self, dbi: u32) {
+        self.0.dbi_open_mutex.lock().unwrap().remove(&dbi);
+        self.comparators().remove(&dbi);
+    }
+
+    /// Converts an error returned by a write operation, schedules a resize of the
+    /// memory map when it is full and the auto resize mode is enabled.
+    pub(crate) fn write_error(&self, error: MdbError) -> Error {
+        match error {
+            MdbError::MapFull if self.schedule_resize() => Error::MapResized,
+            error => error.into(),
+        }
+    }
+
+    /// Schedules a resize of the memory map if the auto resize mode is enabled,
+    /// returns `false` if it isn't.
+    fn schedule_resize(&self) -> bool {
+        let enabled = self.0.auto_resize.is_some();
+        if enabled {
+            self.0.resize_pending.store(true, Ordering::SeqCst);
+        }
+        enabled
+    }
+
+    /// Registers a transaction that is about to begin, the map isn't resized until it ends.
+    pub(crate) fn txn_began(&self) {
+        *self.0.live_txns.lock().unwrap() += 1;
+    }
+
+    /// Unregisters a transaction that ended, grows the map if it was the last alive one.
+    pub(crate) fn txn_ended(&self) {
+        let mut live_txns = self.0.live_txns.lock().unwrap();
+        *live_txns -= 1;
+        if *live_txns == 0 {
+            // The lock is kept to prevent any transaction from beginning during the resize.
+            self.resize_if_pending();
+        }
+    }
+
+    /// Grows the memory map if a resize was scheduled, no transaction must be alive.
+    fn resize_if_pending(&self) {
+        let factor = match self.0.auto_resize {
+            Some(factor) if self.0.resize_pending.load(Ordering::SeqCst) => factor,
+            _ => return,
+        };
+
+        let map_size = match self.info() {
+            Ok(info) => info.map_size,
+            Err(_) => return,
+        };
+
+        let page_size = page_size::get();
+        let new_size = (map_size as f64 * factor) as usize;
+        let new_size = (new_size / page_size + 1) * page_size;
+
+        // safety: no transaction of this process is alive and none can begin, LMDB refuses
+        //         to resize the map if a write transaction of another process is alive,
+        //         we keep the resize pending and retry at the end of the next one.
+        let result = unsafe { mdb_result(ffi::mdb_env_set_mapsize(self.0.env, new_size)) };
+        if result.is_ok() {
+            self.0.resize_pending.store(false, Ordering::SeqCst);
+        }
+    }
+
     /// Resize the memory map to a new size.
     ///
     /// # Safety
@@ -713,12 +1355,55 @@ pub struct EnvInfo {
     pub last_page_number: usize,
     /// ID of the last committed transaction.
     pub last_txn_id: usize,
-    /// Maximum number of reader slots in the environment.
+    /// Maximum number of reader slots in the environment, see [`EnvOpenOptions::max_readers`].
     pub maximum_number_of_readers: u32,
     /// Maximum number of reader slots used in the environment.
     pub number_of_readers: u32,
 }
 
+/// An entry of the reader lock table, see [`Env::reader_list`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReaderInfo {
+    /// ID of the process that holds the reader slot.
+    pub pid: u32,
+    /// ID of the thread that holds the reader slot.
+    pub thread_id: usize,
+    /// ID of the transaction the reader is reading, `None` if it is not reading.
+    pub txn_id: Option<usize>,
+}
+
+impl ReaderInfo {
+    /// Parses a line of the reader lock table formatted by `mdb_reader_list`,
+    /// i.e. the decimal pid, the hexadecimal thread id and the txn id or `-`.
+    fn from_line(line: &str) -> Option<ReaderInfo> {
+        let mut fields = line.split_whitespace();
+        let pid = fields.next()?.parse().ok()?;
+        let thread_id = usize::from_str_radix(fields.next()?, 16).ok()?;
+        let txn_id = match fields.next()? {
+            "-" => None,
+            txn_id => Some(txn_id.parse().ok()?),
+        };
+        Some(ReaderInfo { pid, thread_id, txn_id })
+    }
+}
+
+/// Contains the statistics of a B+tree of the environment.
+#[derive(Debug, Clone, Copy)]
+pub struct EnvStat {
+    /// Size of a database page, the same for all the databases of the environment.
+    pub page_size: u32,
+    /// Depth (height) of the B+tree.
+    pub depth: u32,
+    /// Number of internal (non-leaf) pages.
+    pub branch_pages: usize,
+    /// Number of leaf pages.
+    pub leaf_pages: usize,
+    /// Number of overflow pages.
+    pub overflow_pages: usize,
+    /// Number of data items.
+    pub entries: usize,
+}
+
 /// A structure that can be used to wait for the closing event,
 /// multiple threads can wait on this event.
 #[derive(Clone)]
@@ -755,7 +1440,7 @@ mod tests {
     use std::{fs, thread};
 
     use crate::types::*;
-    use crate::{env_closing_event, EnvOpenOptions, Error};
+    use crate::{env_closing_event, CompactionOption, EnvOpenOptions, Error, MdbError};
 
     #[test]
     fn close_env() {
@@ -853,6 +1538,258 @@ mod tests {
         let _env = envbuilder.open(&dir.path().join("data.mdb")).unwrap();
     }
 
+    #[test]
+    fn copy_to_file_with_live_readers() {
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(10)
+            .open(&dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Str>(&mut wtxn, Some("my-super-db")).unwrap();
+        db.put(&mut wtxn, "hello", "world").unwrap();
+        db.put(&mut wtxn, "removed", "soon").unwrap();
+        wtxn.commit().unwrap();
+
+        let rtxn = env.read_txn().unwrap();
+        let mut wtxn = env.write_txn().unwrap();
+        db.delete(&mut wtxn, "removed").unwrap();
+        wtxn.commit().unwrap();
+
+        // the copy opens its own read transaction and must run
+        // on another thread than the one holding the reader slot
+        let copy_dir = tempfile::tempdir().unwrap();
+        let path = copy_dir.path().join("data.mdb");
+        let env_cloned = env.clone();
+        thread::spawn(move || env_cloned.copy_to_file(path, CompactionOption::Enabled).unwrap())
+            .join()
+            .unwrap();
+        assert_eq!(db.get(&rtxn, "removed").unwrap(), Some("soon"));
+        drop(rtxn);
+
+        let copy = EnvOpenOptions::new().max_dbs(10).open(copy_dir.path()).unwrap();
+        let rtxn = copy.read_txn().unwrap();
+        let db = copy.open_database::<Str, Str>(&rtxn, Some("my-super-db")).unwrap().unwrap();
+        assert_eq!(db.get(&rtxn, "hello").unwrap(), Some("world"));
+        assert_eq!(db.get(&rtxn, "removed").unwrap(), None);
+    }
+
+    #[test]
+    fn set_compare_again_after_reopening() {
+        use std::cmp::Ordering;
+
+        use crate::Comparator;
+
+        enum Reverse {}
+
+        impl Comparator for Reverse {
+            fn compare(a: &[u8], b: &[u8]) -> Ordering {
+                b.cmp(a)
+            }
+        }
+
+        enum Lexicographic {}
+
+        impl Comparator for Lexicographic {
+            fn compare(a: &[u8], b: &[u8]) -> Ordering {
+                a.cmp(b)
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut options = EnvOpenOptions::new();
+        options.map_size(10 * 1024 * 1024); // 10MB
+        options.max_dbs(10);
+
+        let env = options.open(&dir.path()).unwrap();
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Unit>(&mut wtxn, Some("reversed")).unwrap();
+        db.set_compare::<Reverse>(&mut wtxn).unwrap();
+        db.put(&mut wtxn, "a", &()).unwrap();
+        db.put(&mut wtxn, "b", &()).unwrap();
+        wtxn.commit().unwrap();
+        env.prepare_for_closing().wait();
+
+        let env = options.open(&dir.path()).unwrap();
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Unit>(&mut wtxn, Some("reversed")).unwrap();
+        db.set_compare::<Reverse>(&mut wtxn).unwrap();
+        db.set_compare::<Reverse>(&mut wtxn).unwrap();
+        let result = db.set_compare::<Lexicographic>(&mut wtxn);
+        assert!(matches!(result, Err(Error::ComparatorChanged)));
+
+        let keys: Vec<_> = db.iter(&wtxn).unwrap().map(|r| r.unwrap().0).collect();
+        assert_eq!(keys, ["b", "a"]);
+    }
+
+    #[test]
+    fn open_read_only_without_lock_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new().open(&dir.path()).unwrap();
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Str>(&mut wtxn, None).unwrap();
+        db.put(&mut wtxn, "hello", "world").unwrap();
+        wtxn.commit().unwrap();
+        env.prepare_for_closing().wait();
+
+        let lock_path = dir.path().join("lock.mdb");
+        fs::remove_file(&lock_path).unwrap();
+
+        let mut options = EnvOpenOptions::new();
+        unsafe { options.read_only() };
+        let env = options.open(&dir.path()).unwrap();
+        assert!(!lock_path.exists());
+
+        let rtxn = env.read_txn().unwrap();
+        let db = env.open_database::<Str, Str>(&rtxn, None).unwrap().unwrap();
+        assert_eq!(db.get(&rtxn, "hello").unwrap(), Some("world"));
+        assert!(matches!(env.write_txn(), Err(Error::EnvReadOnly)));
+    }
+
+    #[test]
+    fn read_txn_sendable_needs_no_tls() {
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new().open(&dir.path()).unwrap();
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Str>(&mut wtxn, None).unwrap();
+        db.put(&mut wtxn, "hello", "world").unwrap();
+        wtxn.commit().unwrap();
+
+        // The sync-read-txn feature always opens the environments with the NO_TLS flag.
+        if cfg!(not(feature = "sync-read-txn")) {
+            assert!(matches!(env.read_txn_sendable(), Err(Error::TlsEnabled)));
+        }
+        env.prepare_for_closing().wait();
+
+        let mut options = EnvOpenOptions::new();
+        unsafe { options.flags(crate::EnvFlags::NO_TLS) };
+        let env = options.open(&dir.path()).unwrap();
+        let rtxn = env.read_txn().unwrap();
+        let db = env.open_database::<Str, Str>(&rtxn, None).unwrap().unwrap();
+        drop(rtxn);
+
+        // Many read transactions are opened by the same thread and used by others.
+        let rtxns: Vec<_> = (0..4).map(|_| env.read_txn_sendable().unwrap()).collect();
+        thread::scope(|s| {
+            for rtxn in rtxns {
+                s.spawn(move || assert_eq!(db.get(&rtxn, "hello").unwrap(), Some("world")));
+            }
+        });
+    }
+
+    #[test]
+    fn swap_databases_in_one_transaction() {
+        use crate::{DatabaseFlags, MdbError};
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new().max_dbs(10).open(dir.path()).unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let flags = DatabaseFlags::DUP_SORT;
+        let a = env.create_database_with_flags::<Str, Str>(&mut wtxn, Some("a"), flags).unwrap();
+        let b = env.create_database_with_flags::<Str, Str>(&mut wtxn, Some("b"), flags).unwrap();
+        let _ = env.create_database::<Str, Str>(&mut wtxn, Some("unique")).unwrap();
+        a.put(&mut wtxn, "hello", "a").unwrap();
+        a.put(&mut wtxn, "hello", "aa").unwrap();
+        for i in 0..100 {
+            b.put(&mut wtxn, &i.to_string(), "b").unwrap();
+        }
+        wtxn.commit().unwrap();
+
+        let rtxn = env.read_txn().unwrap();
+        let mut wtxn = env.write_txn().unwrap();
+        env.swap_databases(&mut wtxn, "a", "b").unwrap();
+
+        let result = env.swap_databases(&mut wtxn, "a", "unique");
+        assert!(matches!(result, Err(Error::Mdb(MdbError::Incompatible))));
+        let result = env.swap_databases(&mut wtxn, "a", "missing");
+        assert!(matches!(result, Err(Error::Mdb(MdbError::NotFound))));
+        wtxn.commit().unwrap();
+
+        // A reader that began before the swap still sees the old entries.
+        assert_eq!(a.len(&rtxn).unwrap(), 2);
+        assert_eq!(b.len(&rtxn).unwrap(), 100);
+        drop(rtxn);
+
+        let rtxn = env.read_txn().unwrap();
+        assert_eq!(a.len(&rtxn).unwrap(), 100);
+        assert_eq!(a.get(&rtxn, "42").unwrap(), Some("b"));
+        let values: Vec<_> = b.get_duplicates(&rtxn, "hello").unwrap().unwrap().collect();
+        assert_eq!(
+            values.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+            [("hello", "a"), ("hello", "aa")]
+        );
+        assert_eq!(b.len(&rtxn).unwrap(), 2);
+    }
+
+    #[test]
+    fn readers_share_the_parent_snapshot() {
+        use crate::byteorder::BigEndian;
+
+        type BEU32 = U32<BigEndian>;
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut options = EnvOpenOptions::new();
+        unsafe { options.flags(crate::EnvFlags::NO_TLS) };
+        let env = options.open(dir.path()).unwrap();
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<BEU32, BEU32>(&mut wtxn, None).unwrap();
+        for i in 0..10_000 {
+            db.put(&mut wtxn, &i, &i).unwrap();
+        }
+        wtxn.commit().unwrap();
+
+        let mut rtxn = env.read_txn_sendable().unwrap();
+
+        // The writes done after the parent began are never seen by its readers.
+        let mut wtxn = env.write_txn().unwrap();
+        db.clear(&mut wtxn).unwrap();
+        wtxn.commit().unwrap();
+
+        let readers = rtxn.readers(4);
+        let counts: Vec<usize> = thread::scope(|s| {
+            let workers: Vec<_> = (0..4)
+                .zip(readers)
+                .map(|(n, reader)| {
+                    s.spawn(move || {
+                        let range = n * 2500..(n + 1) * 2500;
+                        reader.range(&db, &range).unwrap().map(Result::unwrap).count()
+                    })
+                })
+                .collect();
+            workers.into_iter().map(|w| w.join().unwrap()).collect()
+        });
+        assert_eq!(counts, vec![2500; 4]);
+
+        drop(rtxn);
+        let rtxn = env.read_txn().unwrap();
+        assert!(db.is_empty(&rtxn).unwrap());
+    }
+
+    #[test]
+    fn readers_only_scan_the_refreshed_databases() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut options = EnvOpenOptions::new();
+        unsafe { options.flags(crate::EnvFlags::NO_TLS) };
+        let env = options.max_dbs(2).open(dir.path()).unwrap();
+        let mut wtxn = env.write_txn().unwrap();
+        let first = env.create_database::<Str, Str>(&mut wtxn, Some("first")).unwrap();
+        wtxn.commit().unwrap();
+
+        let mut rtxn = env.read_txn_sendable().unwrap();
+        let readers = rtxn.readers(1);
+
+        // This database is opened after the readers were created.
+        let mut wtxn = env.write_txn().unwrap();
+        let second = env.create_database::<Str, Str>(&mut wtxn, Some("second")).unwrap();
+        wtxn.commit().unwrap();
+
+        assert!(readers[0].iter(&first).unwrap().next().is_none());
+        assert!(matches!(readers[0].iter(&second), Err(Error::Mdb(MdbError::BadDbi))));
+    }
+
     #[test]
     fn create_database_without_commit() {
         let dir = tempfile::tempdir().unwrap();
@@ -871,6 +1808,171 @@ mod tests {
         assert!(option.is_none());
     }
 
+    #[test]
+    fn create_too_many_databases() {
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(1)
+            .open(&dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let _db = env.create_database::<Str, Str>(&mut wtxn, Some("first")).unwrap();
+        let result = env.create_database::<Str, Str>(&mut wtxn, Some("second"));
+        assert!(matches!(result, Err(Error::DbsFull { max_dbs: 1 })));
+        wtxn.abort();
+    }
+
+    #[test]
+    fn auto_resize_on_map_full() {
+        let dir = tempfile::tempdir().unwrap();
+        let map_size = 16 * page_size::get();
+        let mut envbuilder = EnvOpenOptions::new();
+        envbuilder.map_size(map_size);
+        envbuilder.auto_resize(2.0);
+        let env = envbuilder.open(&dir.path()).unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<OwnedType<u32>, ByteSlice>(&mut wtxn, None).unwrap();
+        wtxn.commit().unwrap();
+
+        // Fill the database until the map is full and ask to retry.
+        let value = vec![0u8; page_size::get()];
+        let mut wtxn = env.write_txn().unwrap();
+        let result = (0..100).try_for_each(|i| db.put(&mut wtxn, &i, &value));
+        assert!(matches!(result, Err(Error::MapResized)));
+        drop(wtxn);
+
+        assert!(env.info().unwrap().map_size >= 2 * map_size);
+
+        let mut wtxn = env.write_txn().unwrap();
+        (0..5).try_for_each(|i| db.put(&mut wtxn, &i, &value)).unwrap();
+        wtxn.commit().unwrap();
+    }
+
+    #[test]
+    fn auto_resize_on_cursor_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let map_size = 16 * page_size::get();
+        let mut envbuilder = EnvOpenOptions::new();
+        envbuilder.map_size(map_size);
+        envbuilder.auto_resize(2.0);
+        let env = envbuilder.open(&dir.path()).unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<OwnedType<u32>, ByteSlice>(&mut wtxn, None).unwrap();
+        (0..100).try_for_each(|i| db.put(&mut wtxn, &i, b"small")).unwrap();
+        wtxn.commit().unwrap();
+
+        // Growing the values through a cursor fills the map too.
+        let value = vec![0u8; page_size::get()];
+        let mut wtxn = env.write_txn().unwrap();
+        let mut iter = db.iter_mut(&mut wtxn).unwrap();
+        let result = (0..100).try_for_each(|_| {
+            let (key, _) = iter.next().unwrap()?;
+            unsafe { iter.put_current(&key, &value)? };
+            Ok(())
+        });
+        assert!(matches!(result, Err(Error::MapResized)));
+        drop(iter);
+        drop(wtxn);
+
+        assert!(env.info().unwrap().map_size >= 2 * map_size);
+    }
+
+    #[test]
+    fn auto_resize_waits_for_the_readers() {
+        let dir = tempfile::tempdir().unwrap();
+        let map_size = 16 * page_size::get();
+        let mut envbuilder = EnvOpenOptions::new();
+        envbuilder.map_size(map_size);
+        envbuilder.auto_resize(2.0);
+        let env = envbuilder.open(&dir.path()).unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<OwnedType<u32>, ByteSlice>(&mut wtxn, None).unwrap();
+        db.put(&mut wtxn, &0, b"hello").unwrap();
+        wtxn.commit().unwrap();
+
+        let rtxn = env.read_txn().unwrap();
+        let hello = db.get(&rtxn, &0).unwrap().unwrap();
+
+        let value = vec![0u8; page_size::get()];
+        let mut wtxn = env.write_txn().unwrap();
+        let result = (1..100).try_for_each(|i| db.put(&mut wtxn, &i, &value));
+        assert!(matches!(result, Err(Error::MapResized)));
+        drop(wtxn);
+
+        // The map isn't moved while the value is borrowed from the read transaction.
+        assert_eq!(env.info().unwrap().map_size, map_size);
+        assert_eq!(hello, b"hello");
+
+        drop(rtxn);
+        assert!(env.info().unwrap().map_size >= 2 * map_size);
+    }
+
+    #[test]
+    fn with_write_txn_retries_on_map_full() {
+        let dir = tempfile::tempdir().unwrap();
+        let map_size = 16 * page_size::get();
+        let mut envbuilder = EnvOpenOptions::new();
+        envbuilder.map_size(map_size);
+        envbuilder.auto_resize(2.0);
+        let env = envbuilder.open(&dir.path()).unwrap();
+
+        let db = env
+            .with_write_txn(|wtxn| env.create_database::<OwnedType<u32>, ByteSlice>(wtxn, None))
+            .unwrap();
+
+        // The transaction is retried with a bigger map until everything fits.
+        let value = vec![0u8; page_size::get()];
+        let mut runs = 0;
+        env.with_write_txn(|wtxn| {
+            runs += 1;
+            (0..100).try_for_each(|i| db.put(wtxn, &i, &value))
+        })
+        .unwrap();
+        assert!(runs > 1);
+        assert!(env.info().unwrap().map_size >= 2 * map_size);
+
+        // Other errors abort the transaction and are returned right away.
+        let mut runs = 0;
+        let result = env.with_write_txn(|wtxn| {
+            runs += 1;
+            db.put(wtxn, &1000, &value)?;
+            Err::<(), _>(Error::InvalidDatabaseTyping)
+        });
+        assert!(matches!(result, Err(Error::InvalidDatabaseTyping)));
+        assert_eq!(runs, 1);
+
+        let rtxn = env.read_txn().unwrap();
+        assert_eq!(db.len(&rtxn).unwrap(), 100);
+        assert_eq!(db.get(&rtxn, &1000).unwrap(), None);
+    }
+
+    #[test]
+    fn reader_list_and_stale_readers() {
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new().open(&dir.path()).unwrap();
+        assert_eq!(env.reader_list().unwrap(), []);
+
+        let rtxn = env.read_txn().unwrap();
+        let last_txn_id = env.info().unwrap().last_txn_id;
+        let readers = env.reader_list().unwrap();
+        assert_eq!(readers.len(), 1);
+        assert_eq!(readers[0].pid, std::process::id());
+        assert_eq!(readers[0].txn_id, Some(last_txn_id));
+
+        // The reader process is alive, its slot isn't stale.
+        assert_eq!(env.clear_stale_readers().unwrap(), 0);
+        drop(rtxn);
+
+        // The thread keeps its slot but doesn't read anymore.
+        let readers = env.reader_list().unwrap();
+        assert!(readers.iter().all(|reader| reader.txn_id.is_none()));
+    }
+
     #[test]
     fn open_already_existing_database() {
         let dir = tempfile::tempdir().unwrap();
@@ -928,6 +2030,33 @@ mod tests {
         }
         wtxn.commit().expect("transaction should commit after resizing the map size");
 
-        assert_eq!(10 * 4096, env.info().map_size);
+        assert_eq!(10 * 4096, env.info().unwrap().map_size);
+    }
+
+    #[test]
+    fn env_info_and_stat() {
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(10)
+            .max_readers(42)
+            .open(&dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let _first = env.create_database::<Str, Str>(&mut wtxn, Some("first")).unwrap();
+        let _second = env.create_database::<Str, Str>(&mut wtxn, Some("second")).unwrap();
+        wtxn.commit().unwrap();
+
+        let _rtxn = env.read_txn().unwrap();
+        let info = env.info().unwrap();
+        assert_eq!(info.map_size, 10 * 1024 * 1024);
+        assert_eq!(info.maximum_number_of_readers, 42);
+        assert!(info.number_of_readers >= 1);
+        assert!(info.last_txn_id >= 1);
+
+        let stat = env.stat().unwrap();
+        assert_eq!(stat.page_size as usize, page_size::get());
+        assert_eq!(stat.entries, 2);
     }
 }
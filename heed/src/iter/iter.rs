@@ -1,18 +1,40 @@
 use std::borrow::Cow;
 use std::marker;
 
+use crate::types::{FixedSize, SizeMismatch};
 use crate::*;
 
 /// A read-only iterator structure.
 pub struct RoIter<'txn, KC, DC> {
     cursor: RoCursor<'txn>,
     move_on_first: bool,
+    only_duplicates: bool,
     _phantom: marker::PhantomData<(KC, DC)>,
 }
 
 impl<'txn, KC, DC> RoIter<'txn, KC, DC> {
     pub(crate) fn new(cursor: RoCursor<'txn>) -> RoIter<'txn, KC, DC> {
-        RoIter { cursor, move_on_first: true, _phantom: marker::PhantomData }
+        RoIter {
+            cursor,
+            move_on_first: true,
+            only_duplicates: false,
+            _phantom: marker::PhantomData,
+        }
+    }
+
+    /// Creates an iterator that starts with the entry following the one the cursor is positioned on.
+    pub(crate) fn new_after_current(cursor: RoCursor<'txn>) -> RoIter<'txn, KC, DC> {
+        RoIter {
+            cursor,
+            move_on_first: false,
+            only_duplicates: false,
+            _phantom: marker::PhantomData,
+        }
+    }
+
+    /// Creates an iterator over the duplicate data of the key the cursor is positioned on.
+    pub(crate) fn new_duplicates(cursor: RoCursor<'txn>) -> RoIter<'txn, KC, DC> {
+        RoIter { cursor, move_on_first: true, only_duplicates: true, _phantom: marker::PhantomData }
     }
 
     /// Change the codec types of this iterator, specifying the codecs.
@@ -20,6 +42,7 @@ impl<'txn, KC, DC> RoIter<'txn, KC, DC> {
         RoIter {
             cursor: self.cursor,
             move_on_first: self.move_on_first,
+            only_duplicates: self.only_duplicates,
             _phantom: marker::PhantomData::default(),
         }
     }
@@ -48,11 +71,18 @@ where
     type Item = Result<(KC::DItem, DC::DItem)>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let result = if self.move_on_first {
-            self.move_on_first = false;
-            self.cursor.move_on_first()
-        } else {
-            self.cursor.move_on_next()
+        let result = match (self.move_on_first, self.only_duplicates) {
+            (true, only_duplicates) => {
+                self.move_on_first = false;
+                // The cursor is already positioned on the first duplicate of the key.
+                if only_duplicates {
+                    self.cursor.current()
+                } else {
+                    self.cursor.move_on_first()
+                }
+            }
+            (false, true) => self.cursor.move_on_next_dup(),
+            (false, false) => self.cursor.move_on_next(),
         };
 
         match result {
@@ -66,7 +96,20 @@ where
     }
 
     fn last(mut self) -> Option<Self::Item> {
-        let result = if self.move_on_first {
+        let result = if self.only_duplicates {
+            if self.move_on_first {
+                self.cursor.move_on_last_dup()
+            } else {
+                // Duplicate data are unique for a given key, we compare them.
+                match (self.cursor.current(), self.cursor.move_on_last_dup()) {
+                    (Ok(Some((_, cdata))), Ok(Some((key, data)))) if cdata != data => {
+                        Ok(Some((key, data)))
+                    }
+                    (Ok(_), Ok(_)) => Ok(None),
+                    (Err(e), _) | (_, Err(e)) => Err(e),
+                }
+            }
+        } else if self.move_on_first {
             self.cursor.move_on_last()
         } else {
             match (self.cursor.current(), self.cursor.move_on_last()) {
@@ -89,6 +132,72 @@ where
     }
 }
 
+/// A read-only iterator over the duplicate values of a key, read a page at a time.
+///
+/// Only works on databases created with the [`DatabaseFlags::DUP_FIXED`] flag.
+pub struct RoBulkDuplicates<'txn, DC> {
+    cursor: RoCursor<'txn>,
+    move_on_first: bool,
+    chunk: &'txn [u8],
+    _phantom: marker::PhantomData<DC>,
+}
+
+impl<'txn, DC> RoBulkDuplicates<'txn, DC> {
+    pub(crate) fn new(cursor: RoCursor<'txn>) -> RoBulkDuplicates<'txn, DC> {
+        RoBulkDuplicates { cursor, move_on_first: true, chunk: &[], _phantom: marker::PhantomData }
+    }
+
+    /// Change the data codec type of this iterator, specifying the new data codec.
+    pub fn remap_data_type<DC2>(self) -> RoBulkDuplicates<'txn, DC2> {
+        RoBulkDuplicates {
+            cursor: self.cursor,
+            move_on_first: self.move_on_first,
+            chunk: self.chunk,
+            _phantom: marker::PhantomData,
+        }
+    }
+}
+
+impl<'txn, DC> Iterator for RoBulkDuplicates<'txn, DC>
+where
+    DC: FixedSize + BytesDecode<'txn>,
+{
+    type Item = Result<DC::DItem>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.chunk.is_empty() {
+            let result = if self.move_on_first {
+                self.move_on_first = false;
+                // A key with a single value doesn't have any page of duplicates.
+                match self.cursor.get_multiple() {
+                    Ok(None) => self.cursor.current().map(|entry| entry.map(|(_, data)| data)),
+                    result => result,
+                }
+            } else {
+                self.cursor.move_on_next_multiple()
+            };
+
+            match result {
+                // A page that doesn't hold a whole number of values means that
+                // the codec doesn't describe the values stored in this database.
+                Ok(Some(chunk)) if DC::SIZE == 0 || chunk.len() % DC::SIZE != 0 => {
+                    let found = chunk.len();
+                    let expected = found - found.checked_rem(DC::SIZE).unwrap_or(found);
+                    let error = SizeMismatch { expected, found };
+                    return Some(Err(Error::Decoding(error.into())));
+                }
+                Ok(Some(chunk)) => self.chunk = chunk,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        let (data, tail) = self.chunk.split_at(DC::SIZE);
+        self.chunk = tail;
+        Some(DC::bytes_decode(data).map_err(Error::Decoding))
+    }
+}
+
 /// A read-write iterator structure.
 pub struct RwIter<'txn, KC, DC> {
     cursor: RwCursor<'txn>,
@@ -2,25 +2,27 @@ mod iter;
 mod prefix;
 mod range;
 
-pub use self::iter::{RoIter, RoRevIter, RwIter, RwRevIter};
+use crate::types::Bytes;
+
+pub use self::iter::{RoBulkDuplicates, RoIter, RoRevIter, RwIter, RwRevIter};
 pub use self::prefix::{RoPrefix, RoRevPrefix, RwPrefix, RwRevPrefix};
 pub use self::range::{RoRange, RoRevRange, RwRange, RwRevRange};
 
-fn advance_key(bytes: &mut Vec<u8>) {
-    match bytes.last_mut() {
-        Some(&mut 255) | None => bytes.push(0),
-        Some(last) => *last += 1,
-    }
-}
-
-fn retreat_key(bytes: &mut Vec<u8>) {
-    match bytes.last_mut() {
-        Some(&mut 0) => {
-            bytes.pop();
+/// A read-only iterator that yields the raw key and data bytes of every entry,
+/// without going through any codec.
+pub type RoIterRaw<'txn> = RoIter<'txn, Bytes, Bytes>;
+
+/// Returns the smallest key that is greater than all the keys starting with the prefix,
+/// there is none when the prefix is empty or only made of `255` bytes.
+fn prefix_successor(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut successor = prefix.to_vec();
+    while let Some(last) = successor.pop() {
+        if last != 255 {
+            successor.push(last + 1);
+            return Some(successor);
         }
-        Some(last) => *last -= 1,
-        None => panic!("Vec is empty and must not be"),
     }
+    None
 }
 
 #[cfg(test)]
@@ -64,6 +66,485 @@ mod tests {
         wtxn.abort();
     }
 
+    #[test]
+    fn iter_decode_ignore() {
+        use crate::types::*;
+        use crate::EnvOpenOptions;
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, ByteSlice>(&mut wtxn, None).unwrap();
+        wtxn.commit().unwrap();
+
+        // Store data that can't be decoded as a valid string...
+        let mut wtxn = env.write_txn().unwrap();
+        db.put(&mut wtxn, "hello", &[255, 254]).unwrap();
+        db.put(&mut wtxn, "world", &[253, 252]).unwrap();
+
+        // ...and make sure that ignoring the data never tries to decode it.
+        let db = db.remap_data_type::<DecodeIgnore>();
+        assert_eq!(db.iter(&wtxn).unwrap().count(), 2);
+        assert_eq!(db.rev_iter(&wtxn).unwrap().count(), 2);
+        assert_eq!(db.prefix_iter(&wtxn, "wor").unwrap().count(), 1);
+
+        let mut iter = db.iter(&wtxn).unwrap();
+        assert_eq!(iter.next().transpose().unwrap(), Some(("hello", ())));
+        assert_eq!(iter.next().transpose().unwrap(), Some(("world", ())));
+        assert_eq!(iter.next().transpose().unwrap(), None);
+        drop(iter);
+
+        let db = db.remap_data_type::<Str>();
+        assert!(db.iter(&wtxn).unwrap().next().unwrap().is_err());
+
+        wtxn.abort();
+    }
+
+    #[test]
+    fn range_big_endian_integers() {
+        use crate::types::*;
+        use crate::EnvOpenOptions;
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<BEU64, Unit>(&mut wtxn, None).unwrap();
+        wtxn.commit().unwrap();
+
+        // Insert keys whose little-endian representation isn't ordered.
+        let mut wtxn = env.write_txn().unwrap();
+        for i in [256, 1, 600, 499, 100, 255, 500] {
+            db.put(&mut wtxn, &i, &()).unwrap();
+        }
+
+        let keys: Vec<_> = db.range(&wtxn, &(100..500)).unwrap().map(|r| r.unwrap().0).collect();
+        assert_eq!(keys, [100, 255, 256, 499]);
+
+        wtxn.abort();
+    }
+
+    #[test]
+    fn prefix_iter_mut_bump_counters() {
+        use crate::byteorder::BigEndian;
+        use crate::types::*;
+        use crate::EnvOpenOptions;
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        type BEU64 = U64<BigEndian>;
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, BEU64>(&mut wtxn, None).unwrap();
+        db.put(&mut wtxn, "counter:a", &1).unwrap();
+        db.put(&mut wtxn, "counter:b", &2).unwrap();
+        db.put(&mut wtxn, "gauge:a", &10).unwrap();
+
+        let mut iter = db.prefix_iter_mut(&mut wtxn, "counter:").unwrap();
+        while let Some((key, count)) = iter.next().transpose().unwrap() {
+            let key = key.to_owned();
+            assert!(unsafe { iter.put_current(&key, &(count + 1)).unwrap() });
+        }
+        drop(iter);
+
+        let all: Vec<_> = db.iter(&wtxn).unwrap().map(Result::unwrap).collect();
+        assert_eq!(all, [("counter:a", 2), ("counter:b", 3), ("gauge:a", 10)]);
+    }
+
+    #[test]
+    fn iter_integer_keys() {
+        use crate::byteorder::NativeEndian;
+        use crate::types::*;
+        use crate::EnvOpenOptions;
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        type NEU32 = U32<NativeEndian>;
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_integer_database::<NEU32, Unit>(&mut wtxn, Some("ints")).unwrap();
+        for i in [65537, 1, 256, 65536, 255] {
+            db.put(&mut wtxn, &i, &()).unwrap();
+        }
+        wtxn.commit().unwrap();
+
+        let rtxn = env.read_txn().unwrap();
+        let db = env.open_database::<NEU32, Unit>(&rtxn, Some("ints")).unwrap().unwrap();
+
+        // LMDB compares the keys as integers, not as native endian bytes.
+        let keys: Vec<_> = db.iter(&rtxn).unwrap().map(|r| r.unwrap().0).collect();
+        assert_eq!(keys, [1, 255, 256, 65536, 65537]);
+        let rev: Vec<_> = db.rev_iter(&rtxn).unwrap().map(|r| r.unwrap().0).collect();
+        assert_eq!(rev, [65537, 65536, 256, 255, 1]);
+    }
+
+    #[test]
+    fn range_integer_keys() {
+        use std::ops::Bound;
+
+        use crate::byteorder::NativeEndian;
+        use crate::types::*;
+        use crate::{EnvOpenOptions, RoRange};
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        type NEU32 = U32<NativeEndian>;
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_integer_database::<NEU32, Unit>(&mut wtxn, Some("ints")).unwrap();
+        for i in [1, 255, 256, 65536, 65537] {
+            db.put(&mut wtxn, &i, &()).unwrap();
+        }
+        wtxn.commit().unwrap();
+
+        let rtxn = env.read_txn().unwrap();
+        let db = env.open_database::<NEU32, Unit>(&rtxn, Some("ints")).unwrap().unwrap();
+
+        let keys = |iter: RoRange<NEU32, Unit>| iter.map(|r| r.unwrap().0).collect::<Vec<_>>();
+        assert_eq!(keys(db.range(&rtxn, &(255..65537)).unwrap()), [255, 256, 65536]);
+        assert_eq!(keys(db.range(&rtxn, &(2..=256)).unwrap()), [255, 256]);
+
+        let range = (Bound::Excluded(255), Bound::Included(65536));
+        assert_eq!(keys(db.range(&rtxn, &range).unwrap()), [256, 65536]);
+
+        let rev: Vec<_> = db.rev_range(&rtxn, &(..256)).unwrap().map(|r| r.unwrap().0).collect();
+        assert_eq!(rev, [255, 1]);
+    }
+
+    #[test]
+    fn get_nearest_keys() {
+        use crate::byteorder::BigEndian;
+        use crate::types::*;
+        use crate::EnvOpenOptions;
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        type BEU32 = U32<BigEndian>;
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<BEU32, Unit>(&mut wtxn, None).unwrap();
+        assert_eq!(db.get_lower_than(&wtxn, &10).unwrap(), None);
+        assert_eq!(db.get_greater_than_or_equal_to(&wtxn, &10).unwrap(), None);
+
+        for i in [10, 20, 30] {
+            db.put(&mut wtxn, &i, &()).unwrap();
+        }
+
+        assert_eq!(db.get_lower_than(&wtxn, &10).unwrap(), None);
+        assert_eq!(db.get_lower_than(&wtxn, &20).unwrap(), Some((10, ())));
+        assert_eq!(db.get_lower_than(&wtxn, &25).unwrap(), Some((20, ())));
+        assert_eq!(db.get_lower_than(&wtxn, &35).unwrap(), Some((30, ())));
+
+        assert_eq!(db.get_lower_than_or_equal_to(&wtxn, &5).unwrap(), None);
+        assert_eq!(db.get_lower_than_or_equal_to(&wtxn, &20).unwrap(), Some((20, ())));
+        assert_eq!(db.get_lower_than_or_equal_to(&wtxn, &25).unwrap(), Some((20, ())));
+        assert_eq!(db.get_lower_than_or_equal_to(&wtxn, &35).unwrap(), Some((30, ())));
+
+        assert_eq!(db.get_greater_than(&wtxn, &5).unwrap(), Some((10, ())));
+        assert_eq!(db.get_greater_than(&wtxn, &20).unwrap(), Some((30, ())));
+        assert_eq!(db.get_greater_than(&wtxn, &25).unwrap(), Some((30, ())));
+        assert_eq!(db.get_greater_than(&wtxn, &30).unwrap(), None);
+
+        assert_eq!(db.get_greater_than_or_equal_to(&wtxn, &5).unwrap(), Some((10, ())));
+        assert_eq!(db.get_greater_than_or_equal_to(&wtxn, &20).unwrap(), Some((20, ())));
+        assert_eq!(db.get_greater_than_or_equal_to(&wtxn, &25).unwrap(), Some((30, ())));
+        assert_eq!(db.get_greater_than_or_equal_to(&wtxn, &35).unwrap(), None);
+    }
+
+    #[test]
+    fn len_counts_duplicates() {
+        use crate::byteorder::BigEndian;
+        use crate::types::*;
+        use crate::{DatabaseFlags, EnvOpenOptions};
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        type BEI32 = I32<BigEndian>;
+
+        let mut wtxn = env.write_txn().unwrap();
+        let flags = DatabaseFlags::DUP_SORT;
+        let db = env.create_database_with_flags::<BEI32, BEI32>(&mut wtxn, None, flags).unwrap();
+        assert!(db.is_empty(&wtxn).unwrap());
+
+        db.put(&mut wtxn, &1, &3).unwrap();
+        db.put(&mut wtxn, &2, &3).unwrap();
+        db.put(&mut wtxn, &2, &1).unwrap();
+        db.put(&mut wtxn, &2, &1).unwrap();
+        wtxn.commit().unwrap();
+
+        let rtxn = env.read_txn().unwrap();
+        assert_eq!(db.len(&rtxn).unwrap(), 3);
+        assert!(!db.is_empty(&rtxn).unwrap());
+        drop(rtxn);
+    }
+
+    #[test]
+    fn delete_database_then_recreate_with_other_types() {
+        use crate::types::*;
+        use crate::EnvOpenOptions;
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Str>(&mut wtxn, Some("tenant")).unwrap();
+        db.put(&mut wtxn, "hello", "world").unwrap();
+        wtxn.commit().unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        unsafe { db.delete_database(&mut wtxn).unwrap() };
+        assert!(env.open_database::<Str, Str>(&wtxn, Some("tenant")).unwrap().is_none());
+
+        let db = env.create_database::<Str, Unit>(&mut wtxn, Some("tenant")).unwrap();
+        assert!(db.is_empty(&wtxn).unwrap());
+        db.put(&mut wtxn, "hello", &()).unwrap();
+        wtxn.commit().unwrap();
+
+        let rtxn = env.read_txn().unwrap();
+        assert_eq!(db.get(&rtxn, "hello").unwrap(), Some(()));
+        drop(rtxn);
+    }
+
+    #[test]
+    fn delete_unnamed_database_keeps_its_types() {
+        use crate::types::*;
+        use crate::{EnvOpenOptions, Error};
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Str>(&mut wtxn, None).unwrap();
+        db.put(&mut wtxn, "hello", "world").unwrap();
+        unsafe { db.delete_database(&mut wtxn).unwrap() };
+
+        // LMDB only emptied the unnamed database, its handle is still open with the same types.
+        let result = env.create_database::<Str, Unit>(&mut wtxn, None);
+        assert!(matches!(result, Err(Error::InvalidDatabaseTyping)));
+        let result = env.open_database::<Str, Unit>(&wtxn, None);
+        assert!(matches!(result, Err(Error::InvalidDatabaseTyping)));
+
+        let db = env.open_database::<Str, Str>(&wtxn, None).unwrap().unwrap();
+        assert!(db.is_empty(&wtxn).unwrap());
+        db.put(&mut wtxn, "hello", "again").unwrap();
+        wtxn.commit().unwrap();
+
+        let rtxn = env.read_txn().unwrap();
+        assert_eq!(db.get(&rtxn, "hello").unwrap(), Some("again"));
+    }
+
+    #[test]
+    fn iter_from_edges() {
+        use crate::byteorder::BigEndian;
+        use crate::types::*;
+        use crate::{DatabaseFlags, EnvOpenOptions};
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        type BEI32 = I32<BigEndian>;
+
+        let mut wtxn = env.write_txn().unwrap();
+        let flags = DatabaseFlags::DUP_SORT;
+        let db = env.create_database_with_flags::<BEI32, BEI32>(&mut wtxn, None, flags).unwrap();
+        assert_eq!(db.iter_from(&wtxn, &0).unwrap().next().transpose().unwrap(), None);
+
+        db.put(&mut wtxn, &1, &1).unwrap();
+        db.put(&mut wtxn, &2, &1).unwrap();
+        db.put(&mut wtxn, &2, &2).unwrap();
+        db.put(&mut wtxn, &4, &1).unwrap();
+        wtxn.commit().unwrap();
+
+        let rtxn = env.read_txn().unwrap();
+        let entries = |after| db.iter_from(&rtxn, &after).unwrap().map(Result::unwrap);
+        assert_eq!(entries(0).collect::<Vec<_>>(), [(1, 1), (2, 1), (2, 2), (4, 1)]);
+        assert_eq!(entries(1).collect::<Vec<_>>(), [(2, 1), (2, 2), (4, 1)]);
+        assert_eq!(entries(2).collect::<Vec<_>>(), [(4, 1)]);
+        assert_eq!(entries(3).collect::<Vec<_>>(), [(4, 1)]);
+        assert_eq!(entries(4).collect::<Vec<_>>(), []);
+        assert_eq!(entries(5).collect::<Vec<_>>(), []);
+
+        assert_eq!(entries(1).last(), Some((4, 1)));
+        assert_eq!(entries(4).last(), None);
+        drop(rtxn);
+    }
+
+    #[test]
+    fn get_duplicates_last() {
+        use crate::byteorder::BigEndian;
+        use crate::types::*;
+        use crate::{DatabaseFlags, EnvOpenOptions};
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        type BEI32 = I32<BigEndian>;
+
+        let mut wtxn = env.write_txn().unwrap();
+        let flags = DatabaseFlags::DUP_SORT;
+        let db = env.create_database_with_flags::<BEI32, BEI32>(&mut wtxn, None, flags).unwrap();
+        wtxn.commit().unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        db.put(&mut wtxn, &1, &3).unwrap();
+        db.put(&mut wtxn, &2, &3).unwrap();
+        db.put(&mut wtxn, &2, &1).unwrap();
+        db.put(&mut wtxn, &2, &2).unwrap();
+        db.put(&mut wtxn, &3, &1).unwrap();
+
+        let iter = db.get_duplicates(&wtxn, &2).unwrap().unwrap();
+        assert_eq!(iter.last().transpose().unwrap(), Some((2, 3)));
+
+        let mut iter = db.get_duplicates(&wtxn, &2).unwrap().unwrap();
+        assert_eq!(iter.next().transpose().unwrap(), Some((2, 1)));
+        assert_eq!(iter.last().transpose().unwrap(), Some((2, 3)));
+
+        let mut iter = db.get_duplicates(&wtxn, &2).unwrap().unwrap();
+        assert_eq!(iter.next().transpose().unwrap(), Some((2, 1)));
+        assert_eq!(iter.next().transpose().unwrap(), Some((2, 2)));
+        assert_eq!(iter.next().transpose().unwrap(), Some((2, 3)));
+        assert_eq!(iter.last().transpose().unwrap(), None);
+
+        let mut iter = db.get_duplicates(&wtxn, &3).unwrap().unwrap();
+        assert_eq!(iter.next().transpose().unwrap(), Some((3, 1)));
+        assert_eq!(iter.next().transpose().unwrap(), None);
+        drop(iter);
+
+        // The whole database iterates over every duplicate.
+        assert_eq!(db.iter(&wtxn).unwrap().count(), 5);
+        assert_eq!(db.len(&wtxn).unwrap(), 5);
+
+        wtxn.abort();
+    }
+
+    #[test]
+    fn delete_range_bounds() {
+        use std::ops::Bound;
+
+        use crate::byteorder::BigEndian;
+        use crate::types::*;
+        use crate::EnvOpenOptions;
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        type BEI32 = I32<BigEndian>;
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<BEI32, Unit>(&mut wtxn, None).unwrap();
+        wtxn.commit().unwrap();
+
+        // Check that we delete exactly the keys that the range iterator returns.
+        let ranges = [
+            (Bound::Included(2), Bound::Included(4)),
+            (Bound::Included(2), Bound::Excluded(4)),
+            (Bound::Excluded(2), Bound::Included(4)),
+            (Bound::Excluded(2), Bound::Excluded(4)),
+            (Bound::Unbounded, Bound::Excluded(3)),
+            (Bound::Included(3), Bound::Unbounded),
+            (Bound::Unbounded, Bound::Unbounded),
+            (Bound::Included(4), Bound::Included(2)),
+        ];
+
+        for range in ranges {
+            let mut wtxn = env.write_txn().unwrap();
+            for i in 1..=5 {
+                db.put(&mut wtxn, &i, &()).unwrap();
+            }
+
+            let expected: Vec<_> = db.range(&wtxn, &range).unwrap().map(|r| r.unwrap().0).collect();
+            let deleted = db.delete_range(&mut wtxn, &range).unwrap();
+            assert_eq!(deleted, expected.len());
+
+            let remaining: Vec<_> = db.iter(&wtxn).unwrap().map(|r| r.unwrap().0).collect();
+            let mut all: Vec<_> = remaining.iter().chain(&expected).copied().collect();
+            all.sort_unstable();
+            assert_eq!(all, [1, 2, 3, 4, 5]);
+
+            wtxn.abort();
+        }
+    }
+
+    #[test]
+    fn signed_big_endian_keys_follow_the_numeric_order() {
+        use crate::types::*;
+        use crate::EnvOpenOptions;
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<BEI32, Unit>(&mut wtxn, None).unwrap();
+        for i in [i32::MIN, -10, -5, -1, 0, 1, 4, 5, 10, i32::MAX] {
+            db.put(&mut wtxn, &i, &()).unwrap();
+        }
+
+        let keys: Vec<_> = db.range(&wtxn, &(-5..5)).unwrap().map(|r| r.unwrap().0).collect();
+        assert_eq!(keys, [-5, -1, 0, 1, 4]);
+        let keys: Vec<_> = db.iter(&wtxn).unwrap().map(|r| r.unwrap().0).collect();
+        assert_eq!(keys, [i32::MIN, -10, -5, -1, 0, 1, 4, 5, 10, i32::MAX]);
+
+        wtxn.abort();
+    }
+
     #[test]
     fn iter_last() {
         use crate::byteorder::BigEndian;
@@ -299,6 +780,58 @@ mod tests {
         wtxn.abort();
     }
 
+    #[test]
+    fn rev_prefix_iter_with_byte_255() {
+        use crate::types::*;
+        use crate::EnvOpenOptions;
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<ByteSlice, Unit>(&mut wtxn, None).unwrap();
+        wtxn.commit().unwrap();
+
+        // Create an ordered list of keys...
+        let mut wtxn = env.write_txn().unwrap();
+        db.put(&mut wtxn, &[0, 0, 0, 254, 119, 111, 114, 108, 100], &()).unwrap();
+        db.put(&mut wtxn, &[0, 0, 0, 255, 104, 101, 108, 108, 111], &()).unwrap();
+        db.put(&mut wtxn, &[0, 0, 0, 255, 119, 111, 114, 108, 100], &()).unwrap();
+        db.put(&mut wtxn, &[0, 0, 1, 0, 119, 111, 114, 108, 100], &()).unwrap();
+        db.put(&mut wtxn, &[255, 255, 0], &()).unwrap();
+        db.put(&mut wtxn, &[255, 255, 255], &()).unwrap();
+
+        // The successor of the prefix "0, 0, 0, 255" is "0, 0, 1".
+        let mut iter = db.rev_prefix_iter(&wtxn, &[0, 0, 0, 255]).unwrap();
+        assert_eq!(
+            iter.next().transpose().unwrap(),
+            Some((&[0, 0, 0, 255, 119, 111, 114, 108, 100][..], ()))
+        );
+        assert_eq!(
+            iter.next().transpose().unwrap(),
+            Some((&[0, 0, 0, 255, 104, 101, 108, 108, 111][..], ()))
+        );
+        assert_eq!(iter.next().transpose().unwrap(), None);
+        drop(iter);
+
+        // A prefix only made of 255 bytes doesn't have any successor.
+        let mut iter = db.rev_prefix_iter(&wtxn, &[255, 255]).unwrap();
+        assert_eq!(iter.next().transpose().unwrap(), Some((&[255, 255, 255][..], ())));
+        assert_eq!(iter.next().transpose().unwrap(), Some((&[255, 255, 0][..], ())));
+        assert_eq!(iter.next().transpose().unwrap(), None);
+        drop(iter);
+
+        let mut iter = db.rev_prefix_iter(&wtxn, &[0, 0, 0, 255, 255]).unwrap();
+        assert_eq!(iter.next().transpose().unwrap(), None);
+        drop(iter);
+
+        wtxn.abort();
+    }
+
     #[test]
     fn rev_prefix_iter_last() {
         use crate::types::*;
@@ -425,4 +958,257 @@ mod tests {
 
         wtxn.abort();
     }
+
+    #[test]
+    fn bulk_duplicates_spanning_many_pages() {
+        use crate::byteorder::BigEndian;
+        use crate::types::*;
+        use crate::{Database, DatabaseFlags, EnvOpenOptions};
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        type BEI32 = I32<BigEndian>;
+        type Value = OwnedType<[u8; 16]>;
+
+        let mut wtxn = env.write_txn().unwrap();
+        let flags = DatabaseFlags::DUP_SORT | DatabaseFlags::DUP_FIXED;
+        let db: Database<BEI32, Value> =
+            env.create_database_with_flags(&mut wtxn, None, flags).unwrap();
+
+        // Enough 16-bytes values to fill a lot of pages, surrounded by other keys.
+        let values: Vec<[u8; 16]> = (0..10_000u128).map(u128::to_be_bytes).collect();
+        db.put(&mut wtxn, &1, &[255; 16]).unwrap();
+        for value in &values {
+            db.put(&mut wtxn, &2, value).unwrap();
+        }
+        db.put(&mut wtxn, &3, &[0; 16]).unwrap();
+
+        let iter = db.get_duplicates_bulk(&wtxn, &2).unwrap().unwrap();
+        let found: Vec<_> = iter.collect::<Result<_, _>>().unwrap();
+        assert_eq!(found, values);
+
+        let iter = db.get_duplicates_bulk(&wtxn, &3).unwrap().unwrap();
+        assert_eq!(iter.collect::<Result<Vec<_>, _>>().unwrap(), vec![[0; 16]]);
+
+        // A codec of the wrong size can't split the pages it reads.
+        let iter = db.remap_data_type::<OwnedType<[u8; 5]>>().get_duplicates_bulk(&wtxn, &3);
+        assert!(iter.unwrap().unwrap().next().unwrap().is_err());
+
+        wtxn.abort();
+    }
+
+    #[test]
+    fn ranges_follow_the_database_comparator() {
+        use std::cmp::Ordering;
+        use std::ops::Bound::{Excluded, Included, Unbounded};
+
+        use crate::types::*;
+        use crate::{Comparator, EnvOpenOptions, RoRange};
+
+        enum Reverse {}
+
+        impl Comparator for Reverse {
+            fn compare(a: &[u8], b: &[u8]) -> Ordering {
+                b.cmp(a)
+            }
+        }
+
+        enum CaseInsensitive {}
+
+        impl Comparator for CaseInsensitive {
+            fn compare(a: &[u8], b: &[u8]) -> Ordering {
+                a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Unit>(&mut wtxn, Some("reverse")).unwrap();
+        db.set_compare::<Reverse>(&mut wtxn).unwrap();
+        for key in ["a", "b", "c", "d", "e"] {
+            db.put(&mut wtxn, key, &()).unwrap();
+        }
+
+        // The bounds are given in the order of the comparator, here from "d" down to "b".
+        let keys = |iter: RoRange<Str, Unit>| -> Vec<_> {
+            iter.map(|r| r.map(|(k, _)| k.to_string())).collect::<Result<_, _>>().unwrap()
+        };
+        assert_eq!(
+            keys(db.range(&wtxn, &(Included("d"), Included("b"))).unwrap()),
+            ["d", "c", "b"]
+        );
+        assert_eq!(keys(db.range(&wtxn, &(Included("d"), Excluded("b"))).unwrap()), ["d", "c"]);
+        assert_eq!(keys(db.range(&wtxn, &(Unbounded, Excluded("c"))).unwrap()), ["e", "d"]);
+        assert!(keys(db.range(&wtxn, &(Included("b"), Included("d"))).unwrap()).is_empty());
+
+        let rev_keys: Vec<_> = db
+            .rev_range(&wtxn, &(Included("d"), Included("b")))
+            .unwrap()
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(rev_keys, ["b", "c", "d"]);
+        let rev_keys: Vec<_> = db
+            .rev_range(&wtxn, &(Included("d"), Excluded("b")))
+            .unwrap()
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(rev_keys, ["c", "d"]);
+
+        // Bounds that are equal to a key for the comparator but not byte-wise are found too.
+        let db = env.create_database::<Str, Unit>(&mut wtxn, Some("case-insensitive")).unwrap();
+        db.set_compare::<CaseInsensitive>(&mut wtxn).unwrap();
+        for key in ["a", "B", "c"] {
+            db.put(&mut wtxn, key, &()).unwrap();
+        }
+
+        assert_eq!(keys(db.range(&wtxn, &(Included("b"), Unbounded)).unwrap()), ["B", "c"]);
+        assert_eq!(keys(db.range(&wtxn, &(Excluded("b"), Unbounded)).unwrap()), ["c"]);
+        let rev_keys: Vec<_> = db
+            .rev_range(&wtxn, &(Unbounded, Included("b")))
+            .unwrap()
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(rev_keys, ["B", "a"]);
+        let rev_keys: Vec<_> = db
+            .rev_range(&wtxn, &(Unbounded, Excluded("b")))
+            .unwrap()
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(rev_keys, ["a"]);
+
+        wtxn.abort();
+    }
+
+    #[test]
+    fn nearest_keys_follow_the_database_comparator() {
+        use std::cmp::Ordering;
+
+        use crate::types::*;
+        use crate::{Comparator, DatabaseFlags, EnvOpenOptions};
+
+        enum CaseInsensitive {}
+
+        impl Comparator for CaseInsensitive {
+            fn compare(a: &[u8], b: &[u8]) -> Ordering {
+                a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let flags = DatabaseFlags::DUP_SORT;
+        let db = env.create_database_with_flags::<Str, Str>(&mut wtxn, None, flags).unwrap();
+        db.set_compare::<CaseInsensitive>(&mut wtxn).unwrap();
+        db.put(&mut wtxn, "a", "first").unwrap();
+        db.put(&mut wtxn, "B", "first").unwrap();
+        db.put(&mut wtxn, "B", "second").unwrap();
+        db.put(&mut wtxn, "c", "first").unwrap();
+
+        // "b" is equal to "B" for the comparator even though their bytes differ.
+        assert_eq!(db.get_greater_than(&wtxn, "b").unwrap(), Some(("c", "first")));
+        assert_eq!(db.get_greater_than_or_equal_to(&wtxn, "b").unwrap(), Some(("B", "first")));
+        assert_eq!(db.get_lower_than_or_equal_to(&wtxn, "b").unwrap(), Some(("B", "first")));
+        assert_eq!(db.get_lower_than(&wtxn, "b").unwrap(), Some(("a", "first")));
+
+        wtxn.abort();
+    }
+
+    #[test]
+    fn iter_from_follows_the_database_comparator() {
+        use std::cmp::Ordering;
+
+        use crate::types::*;
+        use crate::{Comparator, EnvOpenOptions};
+
+        enum CaseInsensitive {}
+
+        impl Comparator for CaseInsensitive {
+            fn compare(a: &[u8], b: &[u8]) -> Ordering {
+                a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Unit>(&mut wtxn, None).unwrap();
+        db.set_compare::<CaseInsensitive>(&mut wtxn).unwrap();
+        for key in ["a", "B", "c"] {
+            db.put(&mut wtxn, key, &()).unwrap();
+        }
+
+        // "b" is equal to "B" for the comparator, the iteration resumes after it.
+        let keys: Vec<_> = db.iter_from(&wtxn, "b").unwrap().map(|r| r.unwrap().0).collect();
+        assert_eq!(keys, ["c"]);
+        let keys: Vec<_> = db.iter_from(&wtxn, "A").unwrap().map(|r| r.unwrap().0).collect();
+        assert_eq!(keys, ["B", "c"]);
+
+        wtxn.abort();
+    }
+
+    #[test]
+    fn move_key_follows_the_database_comparator() {
+        use std::cmp::Ordering;
+
+        use crate::types::*;
+        use crate::{Comparator, DatabaseFlags, EnvOpenOptions, Error, MdbError};
+
+        enum CaseInsensitive {}
+
+        impl Comparator for CaseInsensitive {
+            fn compare(a: &[u8], b: &[u8]) -> Ordering {
+                a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = EnvOpenOptions::new()
+            .map_size(10 * 1024 * 1024) // 10MB
+            .max_dbs(3000)
+            .open(dir.path())
+            .unwrap();
+
+        let mut wtxn = env.write_txn().unwrap();
+        let db = env.create_database::<Str, Str>(&mut wtxn, Some("case-insensitive")).unwrap();
+        db.set_compare::<CaseInsensitive>(&mut wtxn).unwrap();
+        db.put(&mut wtxn, "hello", "world").unwrap();
+
+        // "HELLO" is equal to "hello" for the comparator, the value must stay.
+        assert!(db.move_key(&mut wtxn, "hello", "HELLO").unwrap());
+        assert_eq!(db.get(&wtxn, "hello").unwrap(), Some("world"));
+
+        // Only the first duplicate would be moved.
+        let flags = DatabaseFlags::DUP_SORT;
+        let dups =
+            env.create_database_with_flags::<Str, Str>(&mut wtxn, Some("dups"), flags).unwrap();
+        dups.put(&mut wtxn, "hello", "first").unwrap();
+        dups.put(&mut wtxn, "hello", "second").unwrap();
+        let result = dups.move_key(&mut wtxn, "hello", "world");
+        assert!(matches!(result, Err(Error::Mdb(MdbError::Incompatible))));
+        assert_eq!(dups.len(&wtxn).unwrap(), 2);
+
+        wtxn.abort();
+    }
 }
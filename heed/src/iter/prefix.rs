@@ -1,18 +1,22 @@
 use std::borrow::Cow;
 use std::marker;
 
-use super::{advance_key, retreat_key};
+use super::prefix_successor;
 use crate::*;
 
 fn move_on_prefix_end<'txn>(
     cursor: &mut RoCursor<'txn>,
-    prefix: &mut Vec<u8>,
+    prefix: &[u8],
 ) -> Result<Option<(&'txn [u8], &'txn [u8])>> {
-    advance_key(prefix);
-    let result =
-        cursor.move_on_key_greater_than_or_equal_to(prefix).and_then(|_| cursor.move_on_prev());
-    retreat_key(prefix);
-    result
+    // We move on the first key that is after all the prefixed ones and step back,
+    // if there is no such key the last key of the database is the last prefixed one.
+    match prefix_successor(prefix) {
+        Some(successor) => match cursor.move_on_key_greater_than_or_equal_to(&successor)? {
+            Some(_) => cursor.move_on_prev(),
+            None => cursor.move_on_last(),
+        },
+        None => cursor.move_on_last(),
+    }
 }
 
 /// A read-only prefix iterator structure.
@@ -87,9 +91,9 @@ where
 
     fn last(mut self) -> Option<Self::Item> {
         let result = if self.move_on_first {
-            move_on_prefix_end(&mut self.cursor, &mut self.prefix)
+            move_on_prefix_end(&mut self.cursor, &self.prefix)
         } else {
-            match (self.cursor.current(), move_on_prefix_end(&mut self.cursor, &mut self.prefix)) {
+            match (self.cursor.current(), move_on_prefix_end(&mut self.cursor, &self.prefix)) {
                 (Ok(Some((ckey, _))), Ok(Some((key, data)))) if ckey != key => {
                     Ok(Some((key, data)))
                 }
@@ -296,9 +300,9 @@ where
 
     fn last(mut self) -> Option<Self::Item> {
         let result = if self.move_on_first {
-            move_on_prefix_end(&mut self.cursor, &mut self.prefix)
+            move_on_prefix_end(&mut self.cursor, &self.prefix)
         } else {
-            match (self.cursor.current(), move_on_prefix_end(&mut self.cursor, &mut self.prefix)) {
+            match (self.cursor.current(), move_on_prefix_end(&mut self.cursor, &self.prefix)) {
                 (Ok(Some((ckey, _))), Ok(Some((key, data)))) if ckey != key => {
                     Ok(Some((key, data)))
                 }
@@ -373,7 +377,7 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         let result = if self.move_on_last {
             self.move_on_last = false;
-            move_on_prefix_end(&mut self.cursor, &mut self.prefix)
+            move_on_prefix_end(&mut self.cursor, &self.prefix)
         } else {
             self.cursor.move_on_prev()
         };
@@ -584,7 +588,7 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         let result = if self.move_on_last {
             self.move_on_last = false;
-            move_on_prefix_end(&mut self.cursor, &mut self.prefix)
+            move_on_prefix_end(&mut self.cursor, &self.prefix)
         } else {
             self.cursor.move_on_prev()
         };
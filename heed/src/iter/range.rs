@@ -2,7 +2,6 @@ use std::borrow::Cow;
 use std::marker;
 use std::ops::Bound;
 
-use super::{advance_key, retreat_key};
 use crate::*;
 
 fn move_on_range_end<'txn>(
@@ -11,7 +10,7 @@ fn move_on_range_end<'txn>(
 ) -> Result<Option<(&'txn [u8], &'txn [u8])>> {
     match end_bound {
         Bound::Included(end) => match cursor.move_on_key_greater_than_or_equal_to(end) {
-            Ok(Some((key, data))) if key == &end[..] => Ok(Some((key, data))),
+            Ok(Some((key, data))) if cursor.compare_keys(key, end).is_eq() => Ok(Some((key, data))),
             Ok(_) => cursor.move_on_prev(),
             Err(e) => Err(e),
         },
@@ -24,16 +23,16 @@ fn move_on_range_end<'txn>(
 
 fn move_on_range_start<'txn>(
     cursor: &mut RoCursor<'txn>,
-    start_bound: &mut Bound<Vec<u8>>,
+    start_bound: &Bound<Vec<u8>>,
 ) -> Result<Option<(&'txn [u8], &'txn [u8])>> {
     match start_bound {
         Bound::Included(start) => cursor.move_on_key_greater_than_or_equal_to(start),
-        Bound::Excluded(start) => {
-            advance_key(start);
-            let result = cursor.move_on_key_greater_than_or_equal_to(start);
-            retreat_key(start);
-            result
-        }
+        Bound::Excluded(start) => match cursor.move_on_key_greater_than_or_equal_to(start)? {
+            Some((key, _)) if cursor.compare_keys(key, start).is_eq() => {
+                cursor.move_on_next_nodup()
+            }
+            result => Ok(result),
+        },
         Bound::Unbounded => cursor.move_on_first(),
     }
 }
@@ -99,7 +98,7 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         let result = if self.move_on_start {
             self.move_on_start = false;
-            move_on_range_start(&mut self.cursor, &mut self.start_bound)
+            move_on_range_start(&mut self.cursor, &self.start_bound)
         } else {
             self.cursor.move_on_next()
         };
@@ -107,8 +106,8 @@ where
         match result {
             Ok(Some((key, data))) => {
                 let must_be_returned = match &self.end_bound {
-                    Bound::Included(end) => key <= end,
-                    Bound::Excluded(end) => key < end,
+                    Bound::Included(end) => self.cursor.compare_keys(key, end).is_le(),
+                    Bound::Excluded(end) => self.cursor.compare_keys(key, end).is_lt(),
                     Bound::Unbounded => true,
                 };
 
@@ -142,8 +141,8 @@ where
         match result {
             Ok(Some((key, data))) => {
                 let must_be_returned = match &self.start_bound {
-                    Bound::Included(start) => key >= start,
-                    Bound::Excluded(start) => key > start,
+                    Bound::Included(start) => self.cursor.compare_keys(key, start).is_ge(),
+                    Bound::Excluded(start) => self.cursor.compare_keys(key, start).is_gt(),
                     Bound::Unbounded => true,
                 };
 
@@ -332,7 +331,7 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         let result = if self.move_on_start {
             self.move_on_start = false;
-            move_on_range_start(&mut self.cursor, &mut self.start_bound)
+            move_on_range_start(&mut self.cursor, &self.start_bound)
         } else {
             self.cursor.move_on_next()
         };
@@ -340,8 +339,8 @@ where
         match result {
             Ok(Some((key, data))) => {
                 let must_be_returned = match self.end_bound {
-                    Bound::Included(ref end) => key <= end,
-                    Bound::Excluded(ref end) => key < end,
+                    Bound::Included(ref end) => self.cursor.compare_keys(key, end).is_le(),
+                    Bound::Excluded(ref end) => self.cursor.compare_keys(key, end).is_lt(),
                     Bound::Unbounded => true,
                 };
 
@@ -375,8 +374,8 @@ where
         match result {
             Ok(Some((key, data))) => {
                 let must_be_returned = match &self.start_bound {
-                    Bound::Included(start) => key >= start,
-                    Bound::Excluded(start) => key > start,
+                    Bound::Included(start) => self.cursor.compare_keys(key, start).is_ge(),
+                    Bound::Excluded(start) => self.cursor.compare_keys(key, start).is_gt(),
                     Bound::Unbounded => true,
                 };
 
@@ -464,8 +463,8 @@ where
         match result {
             Ok(Some((key, data))) => {
                 let must_be_returned = match &self.start_bound {
-                    Bound::Included(start) => key >= start,
-                    Bound::Excluded(start) => key > start,
+                    Bound::Included(start) => self.cursor.compare_keys(key, start).is_ge(),
+                    Bound::Excluded(start) => self.cursor.compare_keys(key, start).is_gt(),
                     Bound::Unbounded => true,
                 };
 
@@ -485,10 +484,10 @@ where
 
     fn last(mut self) -> Option<Self::Item> {
         let result = if self.move_on_end {
-            move_on_range_start(&mut self.cursor, &mut self.start_bound)
+            move_on_range_start(&mut self.cursor, &self.start_bound)
         } else {
             let current = self.cursor.current();
-            let start = move_on_range_start(&mut self.cursor, &mut self.start_bound);
+            let start = move_on_range_start(&mut self.cursor, &self.start_bound);
             match (current, start) {
                 (Ok(Some((ckey, _))), Ok(Some((key, data)))) if ckey != key => {
                     Ok(Some((key, data)))
@@ -501,8 +500,8 @@ where
         match result {
             Ok(Some((key, data))) => {
                 let must_be_returned = match &self.end_bound {
-                    Bound::Included(end) => key <= end,
-                    Bound::Excluded(end) => key < end,
+                    Bound::Included(end) => self.cursor.compare_keys(key, end).is_le(),
+                    Bound::Excluded(end) => self.cursor.compare_keys(key, end).is_lt(),
                     Bound::Unbounded => true,
                 };
 
@@ -699,8 +698,8 @@ where
         match result {
             Ok(Some((key, data))) => {
                 let must_be_returned = match &self.start_bound {
-                    Bound::Included(start) => key >= start,
-                    Bound::Excluded(start) => key > start,
+                    Bound::Included(start) => self.cursor.compare_keys(key, start).is_ge(),
+                    Bound::Excluded(start) => self.cursor.compare_keys(key, start).is_gt(),
                     Bound::Unbounded => true,
                 };
 
@@ -720,10 +719,10 @@ where
 
     fn last(mut self) -> Option<Self::Item> {
         let result = if self.move_on_end {
-            move_on_range_start(&mut self.cursor, &mut self.start_bound)
+            move_on_range_start(&mut self.cursor, &self.start_bound)
         } else {
             let current = self.cursor.current();
-            let start = move_on_range_start(&mut self.cursor, &mut self.start_bound);
+            let start = move_on_range_start(&mut self.cursor, &self.start_bound);
             match (current, start) {
                 (Ok(Some((ckey, _))), Ok(Some((key, data)))) if ckey != key => {
                     Ok(Some((key, data)))
@@ -736,8 +735,8 @@ where
         match result {
             Ok(Some((key, data))) => {
                 let must_be_returned = match &self.end_bound {
-                    Bound::Included(end) => key <= end,
-                    Bound::Excluded(end) => key < end,
+                    Bound::Included(end) => self.cursor.compare_keys(key, end).is_le(),
+                    Bound::Excluded(end) => self.cursor.compare_keys(key, end).is_lt(),
                     Bound::Unbounded => true,
                 };
 
@@ -51,7 +51,6 @@ mod cursor;
 mod db;
 mod env;
 mod iter;
-mod lazy_decode;
 mod mdb;
 mod reserved_space;
 mod txn;
@@ -62,19 +61,22 @@ use heed_traits as traits;
 pub use {bytemuck, byteorder, heed_types as types};
 
 use self::cursor::{RoCursor, RwCursor};
-pub use self::db::{Database, PolyDatabase};
-pub use self::env::{env_closing_event, CompactionOption, Env, EnvClosingEvent, EnvOpenOptions};
+pub use self::db::{Database, IntegerKey, PolyDatabase};
+pub use self::env::{
+    env_closing_event, CompactionOption, Env, EnvClosingEvent, EnvInfo, EnvOpenOptions, EnvStat,
+    ReaderInfo, WRITE_TXN_RETRIES,
+};
 pub use self::iter::{
-    RoIter, RoPrefix, RoRange, RoRevIter, RoRevPrefix, RoRevRange, RwIter, RwPrefix, RwRange,
-    RwRevIter, RwRevPrefix, RwRevRange,
+    RoBulkDuplicates, RoIter, RoIterRaw, RoPrefix, RoRange, RoRevIter, RoRevPrefix, RoRevRange,
+    RwIter, RwPrefix, RwRange, RwRevIter, RwRevPrefix, RwRevRange,
 };
-pub use self::lazy_decode::{Lazy, LazyDecode};
 pub use self::mdb::error::Error as MdbError;
 use self::mdb::ffi::{from_val, into_val};
-pub use self::mdb::flags::Flag;
+pub use self::mdb::flags::{DatabaseFlags, EnvFlags, Flag, PutFlags};
 pub use self::reserved_space::ReservedSpace;
-pub use self::traits::{BoxedError, BytesDecode, BytesEncode};
-pub use self::txn::{RoTxn, RwTxn};
+pub use self::traits::{BoxedError, BytesDecode, BytesEncode, Comparator};
+pub use self::txn::{RoTxn, RoTxnReader, RwTxn, SendableRoTxn};
+pub use self::types::{Lazy, LazyDecode};
 
 /// An error that encapsulates all possible errors in this crate.
 #[derive(Debug)]
@@ -85,6 +87,25 @@ pub enum Error {
     Decoding(BoxedError),
     InvalidDatabaseTyping,
     DatabaseClosing,
+    /// The map was full and will be grown, see [`EnvOpenOptions::auto_resize`].
+    ///
+    /// The transaction can't be used anymore and must be retried with a new one.
+    MapResized,
+    /// The environment was opened in read-only mode, see [`EnvOpenOptions::read_only`].
+    EnvReadOnly,
+    /// The environment wasn't opened with the [`EnvFlags::NO_TLS`] flag,
+    /// see [`Env::read_txn_sendable`].
+    TlsEnabled,
+    /// The environment can't open more named databases, see [`EnvOpenOptions::max_dbs`].
+    DbsFull {
+        /// The maximum number of named databases the environment was opened with.
+        max_dbs: u32,
+    },
+    /// A different key comparator was set on a database that already contains entries,
+    /// see [`Database::set_compare`].
+    ComparatorChanged,
+    /// The environment is already opened with different options, e.g. another
+    /// [`EnvOpenOptions::max_readers`] value.
     BadOpenOptions {
         /// The options that were used to originaly open this env.
         options: EnvOpenOptions,
@@ -106,6 +127,24 @@ impl fmt::Display for Error {
             Error::DatabaseClosing => {
                 f.write_str("database is in a closing phase, you can't open it at the same time")
             }
+            Error::MapResized => {
+                f.write_str("the map was full and will be resized, the transaction must be retried")
+            }
+            Error::EnvReadOnly => {
+                f.write_str("the environment is opened in read-only mode, it can't be written")
+            }
+            Error::TlsEnabled => f.write_str(
+                "the environment doesn't use the NO_TLS flag, its read transactions can't be sent",
+            ),
+            Error::DbsFull { max_dbs } => write!(
+                f,
+                "the maximum number of named databases ({}) has been reached, \
+                 consider increasing it with EnvOpenOptions::max_dbs",
+                max_dbs
+            ),
+            Error::ComparatorChanged => f.write_str(
+                "a different key comparator can't be set on a database that already contains entries",
+            ),
             Error::BadOpenOptions { .. } => {
                 f.write_str("an environment is already opened with different options")
             }
@@ -1,3 +1,4 @@
+use bitflags::bitflags;
 use lmdb_master_sys as ffi;
 
 /// LMDB flags (see <http://www.lmdb.tech/doc/group__mdb__env.html> for more details).
@@ -16,3 +17,92 @@ pub enum Flag {
     NoRdAhead = ffi::MDB_NORDAHEAD,
     NoMemInit = ffi::MDB_NOMEMINIT,
 }
+
+bitflags! {
+    /// LMDB environment flags (see <http://www.lmdb.tech/doc/group__mdb__env.html> for more details).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    pub struct EnvFlags: u32 {
+        /// Use a fixed address for the memory map.
+        const FIXED_MAP = ffi::MDB_FIXEDMAP;
+        /// The path is the data file itself and not a directory, the lock file
+        /// is the same path with a `-lock` suffix.
+        const NO_SUB_DIR = ffi::MDB_NOSUBDIR;
+        /// Don't flush the system buffers to disk when committing a transaction.
+        ///
+        /// The last transactions can be lost on a system crash, the database stays intact
+        /// unless the file system doesn't preserve the write order. Calling
+        /// [`Env::force_sync`](crate::Env::force_sync) flushes the buffers.
+        const NO_SYNC = ffi::MDB_NOSYNC;
+        /// Open the environment in read-only mode, no write transaction can be created.
+        const READ_ONLY = ffi::MDB_RDONLY;
+        /// Flush the system buffers when committing a transaction but omit the metadata flush.
+        ///
+        /// The last committed transaction can be lost on a system crash,
+        /// the database stays intact.
+        const NO_META_SYNC = ffi::MDB_NOMETASYNC;
+        /// Use a writeable memory map, the writes don't go through a malloc and a copy.
+        ///
+        /// A stray write through a pointer into the map can silently corrupt the database.
+        const WRITE_MAP = ffi::MDB_WRITEMAP;
+        /// Use asynchronous flushes to disk when used with [`EnvFlags::WRITE_MAP`].
+        ///
+        /// Like with [`EnvFlags::NO_SYNC`] the last transactions can be lost on a system crash.
+        const MAP_ASYNC = ffi::MDB_MAPASYNC;
+        /// Tie the reader locktable slots to the transactions instead of the threads.
+        ///
+        /// The [`Env::read_txn_sendable`](crate::Env::read_txn_sendable) method can then create
+        /// read transactions that are [`Send`], the `sync-read-txn` feature sets this flag and
+        /// makes all of them [`Sync`].
+        const NO_TLS = ffi::MDB_NOTLS;
+        /// Don't do any locking, the caller must manage the concurrency itself.
+        const NO_LOCK = ffi::MDB_NOLOCK;
+        /// Turn off the readahead of the operating system.
+        const NO_READ_AHEAD = ffi::MDB_NORDAHEAD;
+        /// Don't initialize the malloc'd memory before writing it to the disk.
+        const NO_MEM_INIT = ffi::MDB_NOMEMINIT;
+    }
+}
+
+bitflags! {
+    /// LMDB put flags (see <http://www.lmdb.tech/doc/group__mdb__put.html> for more details).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    pub struct PutFlags: u32 {
+        /// Enter the new key/data pair only if it does not already appear in the database.
+        ///
+        /// This flag may only be specified if the database supports duplicates.
+        const NO_DUP_DATA = ffi::MDB_NODUPDATA;
+        /// Enter the new key/data pair only if the key does not already appear in the database.
+        const NO_OVERWRITE = ffi::MDB_NOOVERWRITE;
+        /// Append the given key/data pair to the end of the database.
+        ///
+        /// Loading keys that aren't greater than the last one of the database
+        /// returns a [`MdbError::KeyExist`](crate::MdbError::KeyExist) error.
+        const APPEND = ffi::MDB_APPEND;
+        /// Append the given data to the end of the duplicates of the key.
+        const APPEND_DUP = ffi::MDB_APPENDDUP;
+    }
+}
+
+bitflags! {
+    /// LMDB database flags (see <http://www.lmdb.tech/doc/group__mdb__dbi__open.html> for more details).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    pub struct DatabaseFlags: u32 {
+        /// Duplicate keys may be used in the database.
+        ///
+        /// Keys may have multiple data items, stored in sorted order.
+        const DUP_SORT = ffi::MDB_DUPSORT;
+        /// Duplicate values are all of the same size, only usable with [`DatabaseFlags::DUP_SORT`].
+        ///
+        /// It lets [`Database::get_duplicates_bulk`](crate::Database::get_duplicates_bulk)
+        /// read the duplicate values of a key a whole page at a time.
+        const DUP_FIXED = ffi::MDB_DUPFIXED;
+        /// Keys are binary integers in native byte order, either `u32` or `usize`,
+        /// and must all be of the same size.
+        ///
+        /// Prefer using [`Env::create_integer_database`](crate::Env::create_integer_database).
+        const INTEGER_KEY = ffi::MDB_INTEGERKEY;
+    }
+}
@@ -1,9 +1,11 @@
-use std::ops::Deref;
+use std::ops::{Deref, RangeBounds};
 use std::ptr;
 
 use crate::mdb::error::mdb_result;
 use crate::mdb::ffi;
-use crate::{Env, Result};
+use crate::{
+    BytesEncode, Database, Env, Error, Flag, MdbError, Result, RoCursor, RoIter, RoPrefix, RoRange,
+};
 
 /// A read-only transaction.
 pub struct RoTxn<'e> {
@@ -13,23 +15,27 @@ pub struct RoTxn<'e> {
 
 impl<'e> RoTxn<'e> {
     pub(crate) fn new(env: &'e Env) -> Result<RoTxn<'e>> {
-        let mut txn: *mut ffi::MDB_txn = ptr::null_mut();
+        RoTxn::begin(env, ptr::null_mut(), ffi::MDB_RDONLY)
+    }
+
+    /// Begins a transaction, the map isn't resized while it is alive.
+    fn begin(env: &'e Env, parent: *mut ffi::MDB_txn, flags: u32) -> Result<RoTxn<'e>> {
+        // The transaction is unregistered when dropped, even if it failed to begin.
+        env.txn_began();
+        let mut txn = RoTxn { txn: ptr::null_mut(), env };
 
-        unsafe {
-            mdb_result(ffi::mdb_txn_begin(
-                env.env_mut_ptr(),
-                ptr::null_mut(),
-                ffi::MDB_RDONLY,
-                &mut txn,
-            ))?
-        };
+        unsafe { mdb_result(ffi::mdb_txn_begin(env.env_mut_ptr(), parent, flags, &mut txn.txn))? };
 
-        Ok(RoTxn { txn, env })
+        Ok(txn)
     }
 
     pub(crate) fn env_mut_ptr(&self) -> *mut ffi::MDB_env {
         self.env.env_mut_ptr()
     }
+
+    pub(crate) fn env(&self) -> &'e Env {
+        self.env
+    }
 }
 
 impl Drop for RoTxn<'_> {
@@ -37,12 +43,194 @@ impl Drop for RoTxn<'_> {
         if !self.txn.is_null() {
             abort_txn(self.txn);
         }
+        // The transaction is now ended, we can grow the map if it was too small.
+        self.env.txn_ended();
     }
 }
 
 #[cfg(feature = "sync-read-txn")]
 unsafe impl Sync for RoTxn<'_> {}
 
+/// A read-only transaction that can be sent to another thread.
+///
+/// It can only be created on an environment opened with the [`EnvFlags::NO_TLS`] flag,
+/// see [`Env::read_txn_sendable`].
+///
+/// [`EnvFlags::NO_TLS`]: crate::EnvFlags::NO_TLS
+pub struct SendableRoTxn<'e> {
+    txn: RoTxn<'e>,
+    /// The dbis of the databases refreshed by the last call to [`Self::readers`].
+    refreshed_dbis: Vec<u32>,
+}
+
+impl<'e> SendableRoTxn<'e> {
+    pub(crate) fn new(env: &'e Env) -> Result<SendableRoTxn<'e>> {
+        if !env.contains_flag(Flag::NoTls)? {
+            return Err(Error::TlsEnabled);
+        }
+
+        RoTxn::new(env).map(|txn| SendableRoTxn { txn, refreshed_dbis: Vec::new() })
+    }
+
+    /// Returns the inner read-only transaction, which isn't [`Send`] anymore.
+    pub fn into_inner(self) -> RoTxn<'e> {
+        self.txn
+    }
+
+    /// Creates `n` handles that can be sent to other threads to scan the databases
+    /// with the same snapshot as this transaction.
+    ///
+    /// The readers mutably borrow this transaction, which can't be used while they are alive,
+    /// use [`std::thread::scope`] to give one to each worker. They can only scan the databases
+    /// opened before this call, the other ones return an [`MdbError::BadDbi`] error.
+    /// Only read transactions of an environment opened with the [`EnvFlags::NO_TLS`] flag
+    /// can be shared this way, LMDB ties the write transactions to their thread, which is
+    /// why this method isn't available on a [`RoTxn`].
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use heed::{Database, EnvFlags, EnvOpenOptions};
+    /// use heed::byteorder::BigEndian;
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// let mut options = EnvOpenOptions::new();
+    /// unsafe { options.flags(EnvFlags::NO_TLS) };
+    /// let env = options.open(dir.path())?;
+    /// type BEU32 = U32<BigEndian>;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<BEU32, BEU32> = env.create_database(&mut wtxn, None)?;
+    /// for i in 0..1000 {
+    ///     db.put(&mut wtxn, &i, &i)?;
+    /// }
+    /// wtxn.commit()?;
+    ///
+    /// let mut rtxn = env.read_txn_sendable()?;
+    /// let readers = rtxn.readers(4);
+    /// let sum = thread::scope(|s| {
+    ///     let mut workers = Vec::new();
+    ///     for (start, reader) in (0..1000).step_by(250).zip(readers) {
+    ///         workers.push(s.spawn(move || {
+    ///             let range = reader.range(&db, &(start..start + 250))?;
+    ///             range.map(|r| r.map(|(_, v)| u64::from(v))).sum::<heed::Result<u64>>()
+    ///         }));
+    ///     }
+    ///     workers.into_iter().map(|w| w.join().unwrap()).sum::<heed::Result<u64>>()
+    /// })?;
+    /// assert_eq!(sum, 999 * 1000 / 2);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// A write transaction can't be shared with other threads:
+    ///
+    /// ```compile_fail
+    /// use heed::{EnvFlags, EnvOpenOptions};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// let mut options = EnvOpenOptions::new();
+    /// unsafe { options.flags(EnvFlags::NO_TLS) };
+    /// let env = options.open(dir.path())?;
+    ///
+    /// let mut wtxn = env.write_txn()?;
+    /// let readers = wtxn.readers(4);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`EnvFlags::NO_TLS`]: crate::EnvFlags::NO_TLS
+    pub fn readers(&mut self, n: usize) -> Vec<RoTxnReader<'_, 'e>> {
+        let txn = &self.txn;
+
+        // The first cursor opened on a database in a transaction updates its state,
+        // we do it now for all the known databases so that the readers never do it
+        // and we only let them scan the ones that are valid in this transaction.
+        self.refreshed_dbis.clear();
+        for dbi in txn.env.opened_dbis() {
+            if RoCursor::new(txn, dbi).is_ok() {
+                self.refreshed_dbis.push(dbi);
+            }
+        }
+
+        let dbis = &self.refreshed_dbis[..];
+        (0..n).map(|_| RoTxnReader { txn, dbis }).collect()
+    }
+}
+
+impl<'e> Deref for SendableRoTxn<'e> {
+    type Target = RoTxn<'e>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.txn
+    }
+}
+
+// safety: LMDB doesn't tie the read transactions to the thread that created them
+//         when the environment is opened with the MDB_NOTLS flag, they can only be
+//         used by one thread at a time which is what Send guarantees.
+unsafe impl Send for SendableRoTxn<'_> {}
+
+/// A handle that scans the databases with the snapshot of a read-only transaction,
+/// it can be sent to another thread.
+///
+/// It only gives access to the cursor based iterators, see [`SendableRoTxn::readers`].
+pub struct RoTxnReader<'t, 'e> {
+    txn: &'t RoTxn<'e>,
+    dbis: &'t [u32],
+}
+
+impl<'t, 'e> RoTxnReader<'t, 'e> {
+    /// Returns the transaction if the database was refreshed before creating the readers.
+    fn txn<KC, DC>(&self, db: &Database<KC, DC>) -> Result<&'t RoTxn<'e>> {
+        if self.dbis.contains(&db.dyndb.dbi) {
+            Ok(self.txn)
+        } else {
+            Err(MdbError::BadDbi.into())
+        }
+    }
+
+    /// Returns an iterator over all of the key/value pairs of the database,
+    /// see [`Database::iter`].
+    pub fn iter<KC, DC>(&self, db: &Database<KC, DC>) -> Result<RoIter<'t, KC, DC>> {
+        db.iter(self.txn(db)?)
+    }
+
+    /// Returns an iterator over a range of key/value pairs of the database,
+    /// see [`Database::range`].
+    pub fn range<'a, KC, DC, R>(
+        &self,
+        db: &Database<KC, DC>,
+        range: &'a R,
+    ) -> Result<RoRange<'t, KC, DC>>
+    where
+        KC: BytesEncode<'a>,
+        R: RangeBounds<KC::EItem>,
+    {
+        db.range(self.txn(db)?, range)
+    }
+
+    /// Returns an iterator over the key/value pairs of the database that start
+    /// with the given prefix, see [`Database::prefix_iter`].
+    pub fn prefix_iter<'a, KC, DC>(
+        &self,
+        db: &Database<KC, DC>,
+        prefix: &'a KC::EItem,
+    ) -> Result<RoPrefix<'t, KC, DC>>
+    where
+        KC: BytesEncode<'a>,
+    {
+        db.prefix_iter(self.txn(db)?, prefix)
+    }
+}
+
+// safety: the readers are only created from read transactions of an environment opened
+//         with the MDB_NOTLS flag, they only open cursors on the databases that were refreshed,
+//         which doesn't modify the transaction, and the transaction is mutably borrowed while
+//         they are alive so nothing else can modify it.
+unsafe impl Send for RoTxnReader<'_, '_> {}
+
 fn abort_txn(txn: *mut ffi::MDB_txn) {
     // Asserts that the transaction hasn't been already committed.
     assert!(!txn.is_null());
@@ -56,36 +244,86 @@ pub struct RwTxn<'p> {
 
 impl<'p> RwTxn<'p> {
     pub(crate) fn new(env: &'p Env) -> Result<RwTxn<'p>> {
-        let mut txn: *mut ffi::MDB_txn = ptr::null_mut();
-
-        unsafe { mdb_result(ffi::mdb_txn_begin(env.env_mut_ptr(), ptr::null_mut(), 0, &mut txn))? };
+        if env.contains_flag(Flag::RdOnly)? {
+            return Err(Error::EnvReadOnly);
+        }
 
-        Ok(RwTxn { txn: RoTxn { txn, env } })
+        RoTxn::begin(env, ptr::null_mut(), 0).map(|txn| RwTxn { txn })
     }
 
     pub(crate) fn nested(env: &'p Env, parent: &'p mut RwTxn) -> Result<RwTxn<'p>> {
-        let mut txn: *mut ffi::MDB_txn = ptr::null_mut();
-        let parent_ptr: *mut ffi::MDB_txn = parent.txn.txn;
-
-        unsafe { mdb_result(ffi::mdb_txn_begin(env.env_mut_ptr(), parent_ptr, 0, &mut txn))? };
-
-        Ok(RwTxn { txn: RoTxn { txn, env } })
+        RoTxn::begin(env, parent.txn.txn, 0).map(|txn| RwTxn { txn })
     }
 
     pub(crate) fn env_mut_ptr(&self) -> *mut ffi::MDB_env {
         self.txn.env.env_mut_ptr()
     }
 
+    /// Converts an error returned by a write operation, schedules a resize of the
+    /// memory map when it is full and the auto resize mode is enabled.
+    pub(crate) fn write_error(&self, error: MdbError) -> Error {
+        self.txn.env.write_error(error)
+    }
+
     pub fn commit(mut self) -> Result<()> {
         let result = unsafe { mdb_result(ffi::mdb_txn_commit(self.txn.txn)) };
         self.txn.txn = ptr::null_mut();
-        result.map_err(Into::into)
+        result.map_err(|e| self.write_error(e))
     }
 
     pub fn abort(mut self) {
         abort_txn(self.txn.txn);
         self.txn.txn = ptr::null_mut();
     }
+
+    /// Create a nested transaction with read and write access, a child of this one.
+    ///
+    /// Committing the child merges its changes into this transaction, aborting or dropping it
+    /// only discards the changes made in the child. This transaction can't be used while
+    /// the child is alive.
+    ///
+    /// ```
+    /// # use heed::EnvOpenOptions;
+    /// use heed::Database;
+    /// use heed::types::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dir = tempfile::tempdir()?;
+    /// # let env = EnvOpenOptions::new()
+    /// #     .map_size(10 * 1024 * 1024) // 10MB
+    /// #     .max_dbs(3000)
+    /// #     .open(dir.path())?;
+    /// let mut wtxn = env.write_txn()?;
+    /// let db: Database<Str, Str> = env.create_database(&mut wtxn, Some("batches"))?;
+    ///
+    /// # db.clear(&mut wtxn)?;
+    /// let mut child = wtxn.nested_txn()?;
+    /// db.put(&mut child, "good", "batch")?;
+    /// child.commit()?;
+    ///
+    /// let mut child = wtxn.nested_txn()?;
+    /// db.put(&mut child, "bad", "batch")?;
+    /// child.abort();
+    ///
+    /// assert_eq!(db.get(&wtxn, "good")?, Some("batch"));
+    /// assert_eq!(db.get(&wtxn, "bad")?, None);
+    ///
+    /// wtxn.commit()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn nested_txn(&mut self) -> Result<RwTxn<'_>> {
+        let env = self.txn.env;
+        RwTxn::nested(env, self)
+    }
+}
+
+impl Drop for RwTxn<'_> {
+    fn drop(&mut self) {
+        if !self.txn.txn.is_null() {
+            abort_txn(self.txn.txn);
+            self.txn.txn = ptr::null_mut();
+        }
+    }
 }
 
 impl<'p> Deref for RwTxn<'p> {